@@ -0,0 +1,210 @@
+use crate::{day_dir, Day, Error, InputKind, Part, Year};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// The expected answers for a day, keyed by the input they were computed from.
+///
+/// Answers are stored in `inputs/{year}/{day}/answers.toml`, with one table per input:
+/// ```toml
+/// [sample]
+/// part1 = 3
+/// part2 = 6
+///
+/// [part1]
+/// part1 = 1165
+/// part2 = "Some text answer"
+/// ```
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers(HashMap<(InputKind, Part), String>);
+
+impl Answers {
+    /// Load the answers for the day. A day without an answers file has no answers.
+    pub fn load(year: Year, day: Day) -> Result<Self, Error> {
+        Self::read(&path(year, day))
+    }
+
+    /// Read the answers from the file. A missing file has no answers.
+    pub fn read(path: &Path) -> Result<Self, Error> {
+        match std::fs::read_to_string(path) {
+            Ok(contents) => contents.parse(),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(Error::Io(err)),
+        }
+    }
+
+    pub fn get(&self, input: &InputKind, part: Part) -> Option<&str> {
+        self.0.get(&(input.clone(), part)).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, input: InputKind, part: Part, answer: String) {
+        self.0.insert((input, part), answer);
+    }
+
+    /// Write the answers to the file, replacing anything that was there.
+    pub fn write(&self, path: &Path) -> Result<(), Error> {
+        Ok(std::fs::write(path, self.to_string())?)
+    }
+
+    /// Iterate over the recorded answers, ordered by input and then part.
+    pub fn iter(&self) -> impl Iterator<Item = (InputKind, Part, &str)> {
+        let mut answers = self
+            .0
+            .iter()
+            .map(|((input, part), answer)| (input.clone(), *part, answer.as_str()))
+            .collect::<Vec<_>>();
+        let order = [
+            InputKind::Sample,
+            InputKind::Sample2,
+            InputKind::Part1,
+            InputKind::Part2,
+        ];
+        answers.sort_by_key(|(input, part, _)| {
+            (order.iter().position(|kind| kind == input), *part as u8)
+        });
+        answers.into_iter()
+    }
+}
+
+pub fn path(year: Year, day: Day) -> PathBuf {
+    day_dir(year, day).join("answers.toml")
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut table = None;
+        for (input, part, answer) in self.iter() {
+            if table.as_ref() != Some(&input) {
+                if table.is_some() {
+                    writeln!(f)?;
+                }
+                writeln!(f, "[{input}]")?;
+                table = Some(input);
+            }
+            if answer.parse::<i128>().is_ok() {
+                writeln!(f, "part{part} = {answer}")?;
+            } else {
+                let escaped = answer.replace('\\', "\\\\").replace('"', "\\\"");
+                writeln!(f, "part{part} = \"{escaped}\"")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::str::FromStr for Answers {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = HashMap::new();
+        let mut input: Option<InputKind> = None;
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(table) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                input = match table.trim().parse() {
+                    Ok(InputKind::Path(_) | InputKind::Stdin) | Err(_) => {
+                        return Err(Error::invalid_line(i, format!("Unknown input {table:?}")));
+                    }
+                    Ok(kind) => Some(kind),
+                };
+                continue;
+            }
+
+            let Some(input) = &input else {
+                return Err(Error::invalid_line(
+                    i,
+                    "Answer is not inside an [input] table",
+                ));
+            };
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| Error::invalid_line(i, "Expected 'partN = answer'"))?;
+            let part = match key.trim() {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                key => return Err(Error::invalid_line(i, format!("Unknown part {key:?}"))),
+            };
+            let answer = parse_value(value.trim()).map_err(|e| Error::invalid_line(i, e))?;
+            answers.insert((input.clone(), part), answer);
+        }
+        Ok(Answers(answers))
+    }
+}
+
+/// Parse either a bare integer or a double-quoted string.
+fn parse_value(value: &str) -> Result<String, String> {
+    if let Some(quoted) = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+        let mut answer = String::with_capacity(quoted.len());
+        let mut chars = quoted.chars();
+        while let Some(ch) = chars.next() {
+            match ch {
+                '\\' => match chars.next() {
+                    Some('"') => answer.push('"'),
+                    Some('\\') => answer.push('\\'),
+                    other => return Err(format!("Unsupported escape {other:?}")),
+                },
+                '"' => return Err("Unescaped quote inside string".to_string()),
+                ch => answer.push(ch),
+            }
+        }
+        Ok(answer)
+    } else if value.parse::<i128>().is_ok() {
+        Ok(value.to_string())
+    } else {
+        Err(format!(
+            "Expected an integer or a quoted string, got {value:?}"
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_answers() {
+        let answers = "
+# Comments and blank lines are ignored
+[sample]
+part1 = 3
+
+[part1]
+part1 = -12
+part2 = \"Say \\\"hi\\\"\"
+"
+        .parse::<Answers>()
+        .unwrap();
+
+        assert_eq!(Some("3"), answers.get(&InputKind::Sample, Part::One));
+        assert_eq!(None, answers.get(&InputKind::Sample, Part::Two));
+        assert_eq!(Some("-12"), answers.get(&InputKind::Part1, Part::One));
+        assert_eq!(
+            Some("Say \"hi\""),
+            answers.get(&InputKind::Part1, Part::Two)
+        );
+        assert_eq!(3, answers.iter().count());
+        let text = answers.to_string();
+        assert_eq!(answers, text.parse::<Answers>().unwrap());
+    }
+
+    #[test]
+    fn write_recorded_answers() {
+        for day in 1..=12 {
+            let contents = std::fs::read_to_string(path(2025, day)).unwrap();
+            assert_eq!(contents, Answers::load(2025, day).unwrap().to_string());
+        }
+    }
+
+    #[test]
+    fn parse_invalid_answers() {
+        assert!("part1 = 3".parse::<Answers>().is_err());
+        assert!("[sample]\npart3 = 3".parse::<Answers>().is_err());
+        assert!("[sample]\npart1 = three".parse::<Answers>().is_err());
+        assert!("[example]\npart1 = 3".parse::<Answers>().is_err());
+    }
+}
@@ -0,0 +1,537 @@
+use crate::output::Format;
+use crate::{registry, Day, InputKind, Part, Year};
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::thread;
+
+pub const USAGE: &str = "Usage: advent-of-code [COMMAND] [OPTIONS]
+
+Commands:
+  run      Solve the selected days (default)
+  verify   Check the selected days against the answers in inputs/{year}/{day}/answers.toml
+  new-day  Create and register src/year_{year}/day_{day}.rs and its inputs for the day
+           given by --day
+  fetch    Download the input and examples for the day given by --day, unless they
+           already exist
+  extract  Save the examples and their answers from a puzzle page saved with --html, for
+           the day given by --day
+  watch    Rebuild and solve the day given by --day whenever its source or inputs change
+  submit   Solve the part given by --part of the day given by --day, and submit the answer
+           unless inputs/{year}/{day}/submissions.tsv shows it is wrong
+  help     Print this message
+
+Options:
+  --year <YEAR>    Year of the puzzles [default: the latest solved year]
+  --day <DAYS>     Days to run: 'all', a single day, a range or a comma separated list
+                   of both, e.g. '8', '1-5' or '1,3,5-7' [default: all]
+  --part <PART>    Part to solve: '1', '2' or 'both' [default: both] (run and submit)
+  --input <INPUT>  Input to use: 'sample', 'sample2', 'part1', 'part2', '-' for stdin
+                   (with a single --day), or the path to a file [default: part1]. Stdin
+                   and files are solved as real inputs, which changes the answer of
+                   day 8 part 1 (run only)
+  --format <FMT>   Output format: 'text', 'json', 'csv', or 'visual' for text with a
+                   drawing of the solution of days that have one [default: text] (run only)
+  --jobs <N>       Number of days to solve at once, with the parts of each day solved in
+                   parallel. 'auto' uses one per CPU [default: 1] (run only)
+  --input-dir <DIR>
+                   Directory containing the {year}/{day}/input.* files. Can also be set
+                   with the AOC_INPUT_DIR environment variable [default: inputs]
+  --html <FILE>    Puzzle page to extract the examples from (extract only)
+  --session <TOKEN>
+                   Advent of Code session cookie. Can also be set with the AOC_SESSION
+                   environment variable (fetch and submit)
+  --refresh        Download the puzzle page again to look for the second example, even
+                   if part 1 has not been accepted (fetch only)";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Run(RunArgs),
+    Verify(VerifyArgs),
+    NewDay(NewDayArgs),
+    Fetch(FetchArgs),
+    Submit(SubmitArgs),
+    Watch(WatchArgs),
+    Extract(ExtractArgs),
+    Help,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct RunArgs {
+    pub year: Year,
+    pub days: Vec<Day>,
+    pub part: Part,
+    pub input: InputKind,
+    pub format: Format,
+    pub jobs: usize,
+    pub input_dir: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct VerifyArgs {
+    pub year: Year,
+    pub days: Vec<Day>,
+    pub input_dir: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct NewDayArgs {
+    pub year: Year,
+    pub day: Day,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct FetchArgs {
+    pub year: Year,
+    pub day: Day,
+    pub session: Option<String>,
+    pub input_dir: Option<PathBuf>,
+    pub refresh: bool,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct SubmitArgs {
+    pub year: Year,
+    pub day: Day,
+    pub part: Part,
+    pub session: Option<String>,
+    pub input_dir: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct WatchArgs {
+    pub year: Year,
+    pub day: Day,
+    pub input_dir: Option<PathBuf>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ExtractArgs {
+    pub year: Year,
+    pub day: Day,
+    pub html: PathBuf,
+    pub input_dir: Option<PathBuf>,
+}
+
+impl Default for RunArgs {
+    fn default() -> Self {
+        let year = registry::latest_year().unwrap_or_default();
+        Self {
+            year,
+            days: registry::days(year).map(|entry| entry.day).collect(),
+            part: Part::Both,
+            input: InputKind::Part1,
+            format: Format::Text,
+            jobs: 1,
+            input_dir: None,
+        }
+    }
+}
+
+/// Parse the command line arguments, excluding the program name, into a [`Command`].
+pub fn parse<I>(args: I) -> Result<Command, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let args = args.into_iter().collect::<Vec<_>>();
+    if args.iter().any(|arg| arg == "--help" || arg == "-h") {
+        return Ok(Command::Help);
+    }
+
+    let mut args = args.into_iter().peekable();
+    let command = match args.peek() {
+        Some(arg) if !arg.starts_with("--") => args.next(),
+        _ => None,
+    };
+
+    match command.as_deref() {
+        Some("help") => Ok(Command::Help),
+        None | Some("run") => {
+            let mut run_args = RunArgs::default();
+            let mut days = None;
+            for (flag, value) in flags(args)? {
+                match flag.as_str() {
+                    "--year" => run_args.year = parse_year(&value)?,
+                    "--day" => days = Some(value),
+                    "--part" => run_args.part = parse_part(&value)?,
+                    "--input" => run_args.input = value.parse()?,
+                    "--format" => run_args.format = value.parse()?,
+                    "--jobs" => run_args.jobs = parse_jobs(&value)?,
+                    "--input-dir" => run_args.input_dir = Some(PathBuf::from(value)),
+                    _ => return Err(format!("Unknown option: {flag}")),
+                }
+            }
+            run_args.days = parse_days(run_args.year, days.as_deref().unwrap_or("all"))?;
+            // Stdin can only be read once, so it cannot be shared between days
+            if run_args.input == InputKind::Stdin && run_args.days.len() != 1 {
+                return Err("--input - needs exactly one day to be selected with --day".to_string());
+            }
+            Ok(Command::Run(run_args))
+        }
+        Some("verify") => {
+            let mut verify_args = VerifyArgs {
+                year: RunArgs::default().year,
+                days: Vec::new(),
+                input_dir: None,
+            };
+            let mut days = None;
+            for (flag, value) in flags(args)? {
+                match flag.as_str() {
+                    "--year" => verify_args.year = parse_year(&value)?,
+                    "--day" => days = Some(value),
+                    "--input-dir" => verify_args.input_dir = Some(PathBuf::from(value)),
+                    _ => return Err(format!("Unknown option: {flag}")),
+                }
+            }
+            verify_args.days = parse_days(verify_args.year, days.as_deref().unwrap_or("all"))?;
+            Ok(Command::Verify(verify_args))
+        }
+        Some("new-day") => {
+            let mut year = RunArgs::default().year;
+            let mut day = None;
+            for (flag, value) in flags(args)? {
+                match flag.as_str() {
+                    "--year" => year = parse_year(&value)?,
+                    "--day" => day = Some(parse_day(&value)?),
+                    _ => return Err(format!("Unknown option: {flag}")),
+                }
+            }
+            day.map(|day| Command::NewDay(NewDayArgs { year, day }))
+                .ok_or_else(|| "new-day requires --day".to_string())
+        }
+        Some("fetch") => {
+            let mut year = RunArgs::default().year;
+            let (mut day, mut session, mut input_dir) = (None, None, None);
+            let mut refresh = false;
+            for (flag, value) in flags(args)? {
+                match flag.as_str() {
+                    "--year" => year = parse_year(&value)?,
+                    "--day" => day = Some(parse_day(&value)?),
+                    "--session" => session = Some(value),
+                    "--input-dir" => input_dir = Some(PathBuf::from(value)),
+                    "--refresh" => refresh = true,
+                    _ => return Err(format!("Unknown option: {flag}")),
+                }
+            }
+            let day = day.ok_or("fetch requires --day")?;
+            Ok(Command::Fetch(FetchArgs {
+                year,
+                day,
+                session,
+                input_dir,
+                refresh,
+            }))
+        }
+        Some("submit") => {
+            let mut year = RunArgs::default().year;
+            let (mut day, mut part, mut session, mut input_dir) = (None, None, None, None);
+            for (flag, value) in flags(args)? {
+                match flag.as_str() {
+                    "--year" => year = parse_year(&value)?,
+                    "--day" => day = Some(parse_day(&value)?),
+                    "--part" => part = Some(parse_part(&value)?),
+                    "--session" => session = Some(value),
+                    "--input-dir" => input_dir = Some(PathBuf::from(value)),
+                    _ => return Err(format!("Unknown option: {flag}")),
+                }
+            }
+            let day = day.ok_or("submit requires --day")?;
+            let part = match part {
+                Some(part @ (Part::One | Part::Two)) => part,
+                _ => return Err("submit requires --part 1 or --part 2".to_string()),
+            };
+            if registry::find(year, day).is_none() {
+                return Err(format!("Day {day} of {year} has not been solved"));
+            }
+            Ok(Command::Submit(SubmitArgs {
+                year,
+                day,
+                part,
+                session,
+                input_dir,
+            }))
+        }
+        Some("watch") => {
+            let mut year = RunArgs::default().year;
+            let (mut day, mut input_dir) = (None, None);
+            for (flag, value) in flags(args)? {
+                match flag.as_str() {
+                    "--year" => year = parse_year(&value)?,
+                    "--day" => day = Some(parse_day(&value)?),
+                    "--input-dir" => input_dir = Some(PathBuf::from(value)),
+                    _ => return Err(format!("Unknown option: {flag}")),
+                }
+            }
+            let day = day.ok_or("watch requires --day")?;
+            Ok(Command::Watch(WatchArgs {
+                year,
+                day,
+                input_dir,
+            }))
+        }
+        Some("extract") => {
+            let mut year = RunArgs::default().year;
+            let (mut day, mut html, mut input_dir) = (None, None, None);
+            for (flag, value) in flags(args)? {
+                match flag.as_str() {
+                    "--year" => year = parse_year(&value)?,
+                    "--day" => day = Some(parse_day(&value)?),
+                    "--html" => html = Some(PathBuf::from(value)),
+                    "--input-dir" => input_dir = Some(PathBuf::from(value)),
+                    _ => return Err(format!("Unknown option: {flag}")),
+                }
+            }
+            Ok(Command::Extract(ExtractArgs {
+                year,
+                day: day.ok_or("extract requires --day")?,
+                html: html.ok_or("extract requires --html")?,
+                input_dir,
+            }))
+        }
+        Some(command) => Err(format!("Unknown command: {command}")),
+    }
+}
+
+/// Flags that are given on their own, without a value.
+const SWITCHES: [&str; 1] = ["--refresh"];
+
+/// Pair up each `--flag` with the value that follows it. [`SWITCHES`] have an empty value.
+fn flags<I>(mut args: I) -> Result<Vec<(String, String)>, String>
+where
+    I: Iterator<Item = String>,
+{
+    let mut flags = Vec::new();
+    while let Some(flag) = args.next() {
+        if SWITCHES.contains(&flag.as_str()) {
+            flags.push((flag, String::new()));
+            continue;
+        }
+        let value = args
+            .next()
+            .ok_or_else(|| format!("Missing value for {flag}"))?;
+        flags.push((flag, value));
+    }
+    Ok(flags)
+}
+
+fn parse_part(value: &str) -> Result<Part, String> {
+    match value {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        "both" => Ok(Part::Both),
+        _ => Err(format!("Unknown part: {value}")),
+    }
+}
+
+fn parse_jobs(value: &str) -> Result<usize, String> {
+    if value == "auto" {
+        return Ok(thread::available_parallelism().map_or(1, NonZeroUsize::get));
+    }
+    match value.parse() {
+        Ok(0) | Err(_) => Err(format!("Invalid number of jobs: {value}")),
+        Ok(jobs) => Ok(jobs),
+    }
+}
+
+/// Parse a day of the advent calendar, from 1 to 25.
+fn parse_day(value: &str) -> Result<Day, String> {
+    match value.trim().parse() {
+        Ok(day @ 1..=25) => Ok(day),
+        _ => Err(format!("Invalid day: {value}")),
+    }
+}
+
+fn parse_year(value: &str) -> Result<Year, String> {
+    value.parse().map_err(|_| format!("Invalid year: {value}"))
+}
+
+/// Parse a selection of days such as `all`, `8`, `1-5` or `1,3,5-7`. Every selected day
+/// must exist in the [`registry`] for the year.
+fn parse_days(year: Year, value: &str) -> Result<Vec<Day>, String> {
+    if value == "all" {
+        let days = registry::days(year)
+            .map(|entry| entry.day)
+            .collect::<Vec<_>>();
+        if days.is_empty() {
+            return Err(format!("No days have been solved for {year}"));
+        }
+        return Ok(days);
+    }
+
+    let mut days = Vec::new();
+    for selection in value.split(',') {
+        match selection.split_once('-') {
+            Some((start, end)) => {
+                let (start, end) = (parse_day(start)?, parse_day(end)?);
+                if start > end {
+                    return Err(format!(
+                        "Invalid days {selection}: range start must not exceed end"
+                    ));
+                }
+                days.extend(start..=end);
+            }
+            None => days.push(parse_day(selection)?),
+        }
+    }
+
+    if let Some(day) = days
+        .iter()
+        .find(|&&day| registry::find(year, day).is_none())
+    {
+        return Err(format!("Day {day} of {year} has not been solved"));
+    }
+    days.sort_unstable();
+    days.dedup();
+    Ok(days)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn parse_run() {
+        let command = parse(args(
+            "run --year 2025 --day 8 --part 2 --input sample --format csv",
+        ))
+        .unwrap();
+        let expected = RunArgs {
+            year: 2025,
+            days: vec![8],
+            part: Part::Two,
+            input: InputKind::Sample,
+            format: Format::Csv,
+            jobs: 1,
+            input_dir: None,
+        };
+        assert_eq!(Command::Run(expected), command);
+        assert_eq!(Command::Run(RunArgs::default()), parse(Vec::new()).unwrap());
+        assert!(parse(args("run --day 1 --year 2024")).is_err());
+        assert!(parse(args("run --year 2024")).is_err());
+        assert!(parse(args("solve")).is_err());
+        assert!(parse(args("run --jobs 0")).is_err());
+        assert!(matches!(
+            parse(args("run --jobs auto")).unwrap(),
+            Command::Run(RunArgs { jobs: 1.., .. })
+        ));
+        assert!(matches!(
+            parse(args("run --day 3 --input - --jobs 4")).unwrap(),
+            Command::Run(RunArgs {
+                input: InputKind::Stdin,
+                ..
+            })
+        ));
+        assert!(parse(args("run --day 3-4 --input -")).is_err());
+        assert!(parse(args("run --input -")).is_err());
+    }
+
+    #[test]
+    fn parse_verify() {
+        assert_eq!(
+            Command::Verify(VerifyArgs {
+                year: 2025,
+                days: vec![1, 2],
+                input_dir: Some(PathBuf::from("/tmp/inputs"))
+            }),
+            parse(args("verify --day 1-2 --year 2025 --input-dir /tmp/inputs")).unwrap()
+        );
+        assert!(parse(args("verify --part 1")).is_err());
+    }
+
+    #[test]
+    fn parse_new_day() {
+        assert_eq!(
+            Command::NewDay(NewDayArgs { year: 2026, day: 1 }),
+            parse(args("new-day --day 1 --year 2026")).unwrap()
+        );
+        assert!(parse(args("new-day")).is_err());
+        assert!(parse(args("new-day --day 26")).is_err());
+    }
+
+    #[test]
+    fn parse_fetch() {
+        assert_eq!(
+            Command::Fetch(FetchArgs {
+                year: 2025,
+                day: 3,
+                session: Some("abc".to_string()),
+                input_dir: None,
+                refresh: false,
+            }),
+            parse(args("fetch --year 2025 --day 3 --session abc")).unwrap()
+        );
+        assert_eq!(
+            Command::Fetch(FetchArgs {
+                year: 2025,
+                day: 3,
+                session: None,
+                input_dir: None,
+                refresh: true,
+            }),
+            parse(args("fetch --refresh --year 2025 --day 3")).unwrap()
+        );
+        assert!(parse(args("fetch --year 2025")).is_err());
+        assert!(parse(args("run --refresh")).is_err());
+    }
+
+    #[test]
+    fn parse_submit() {
+        assert_eq!(
+            Command::Submit(SubmitArgs {
+                year: 2025,
+                day: 3,
+                part: Part::Two,
+                session: None,
+                input_dir: None
+            }),
+            parse(args("submit --year 2025 --day 3 --part 2")).unwrap()
+        );
+        assert!(parse(args("submit --year 2025 --day 3 --part both")).is_err());
+        assert!(parse(args("submit --year 2025 --day 13 --part 1")).is_err());
+    }
+
+    #[test]
+    fn parse_watch() {
+        assert_eq!(
+            Command::Watch(WatchArgs {
+                year: 2025,
+                day: 13,
+                input_dir: None
+            }),
+            parse(args("watch --year 2025 --day 13")).unwrap()
+        );
+        assert!(parse(args("watch")).is_err());
+    }
+
+    #[test]
+    fn parse_extract() {
+        assert_eq!(
+            Command::Extract(ExtractArgs {
+                year: 2025,
+                day: 2,
+                html: PathBuf::from("day2.html"),
+                input_dir: None
+            }),
+            parse(args("extract --day 2 --html day2.html --year 2025")).unwrap()
+        );
+        assert!(parse(args("extract --day 2")).is_err());
+    }
+
+    #[test]
+    fn parse_day_selection() {
+        assert_eq!(vec![1, 2, 3, 4, 5], parse_days(2025, "1-5").unwrap());
+        assert_eq!(vec![1, 3, 5, 6, 7], parse_days(2025, "5-7,1,3,6").unwrap());
+        assert_eq!(12, parse_days(2025, "all").unwrap().len());
+        assert!(parse_days(2025, "13").is_err());
+        assert!(parse_days(2025, "one").is_err());
+        assert_eq!(
+            Err("Invalid days 5-1: range start must not exceed end".to_string()),
+            parse_days(2025, "5-1")
+        );
+        assert_eq!(Err("Invalid day: 0".to_string()), parse_days(2025, "0-3"));
+        assert!(parse_days(2024, "all").is_err());
+    }
+}
@@ -0,0 +1,99 @@
+use std::fmt::Display;
+
+/// Errors that can occur while loading and parsing a puzzle [`Input`](crate::Input).
+#[derive(Debug)]
+pub enum Error {
+    /// The input file does not exist.
+    MissingInput(String),
+    /// The input file exists, but could not be read.
+    Io(std::io::Error),
+    /// A line of the input could not be parsed.
+    InvalidLine { line: usize, reason: String },
+    /// A line of the input contained a character that is not part of the puzzle format.
+    UnexpectedChar {
+        line: usize,
+        column: usize,
+        found: char,
+    },
+    /// Part of the input did not match the puzzle format.
+    InvalidInput {
+        line: usize,
+        column: usize,
+        reason: String,
+    },
+    /// Solving the puzzle panicked, with the message the panic carried.
+    Panicked(String),
+}
+
+impl Error {
+    /// Create an [`Error::InvalidLine`] from the zero-based index of the line.
+    pub fn invalid_line<S>(index: usize, reason: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Error::InvalidLine {
+            line: index + 1,
+            reason: reason.into(),
+        }
+    }
+
+    /// Create an [`Error::InvalidInput`] from the zero-based line and column index.
+    pub fn invalid_input<S>(index: usize, column: usize, reason: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Error::InvalidInput {
+            line: index + 1,
+            column: column + 1,
+            reason: reason.into(),
+        }
+    }
+
+    /// Create an [`Error::UnexpectedChar`] from the zero-based line and column index.
+    pub fn unexpected_char(index: usize, column: usize, found: char) -> Self {
+        Error::UnexpectedChar {
+            line: index + 1,
+            column: column + 1,
+            found,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::MissingInput(path) => write!(f, "Missing input file: {path}"),
+            Error::Io(err) => write!(f, "Could not read input: {err}"),
+            Error::InvalidLine { line, reason } => write!(f, "Line {line}: {reason}"),
+            Error::UnexpectedChar {
+                line,
+                column,
+                found,
+            } => write!(
+                f,
+                "Line {line}, column {column}: unexpected character {found:?}"
+            ),
+            Error::InvalidInput {
+                line,
+                column,
+                reason,
+            } => write!(f, "Line {line}, column {column}: {reason}"),
+            Error::Panicked(message) => write!(f, "Panicked: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Error::Io(value)
+    }
+}
@@ -0,0 +1,195 @@
+use crate::answers::Answers;
+use crate::{InputKind, Part};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The example and its expected answer, as given in the description of one part.
+#[derive(Debug, PartialEq, Eq)]
+struct Article {
+    example: Option<String>,
+    answer: Option<String>,
+}
+
+fn articles(html: &str) -> Vec<Article> {
+    html.split("<article")
+        .skip(1)
+        .map(|article| {
+            let article = article.split("</article>").next().unwrap_or(article);
+            let example = between(article, "<pre><code>", "</code></pre>");
+            // The answer to the example is the last highlighted value of the description
+            let answer = ["<code><em>", "<em><code>"]
+                .into_iter()
+                .filter_map(|start| article.rfind(start).map(|i| (i, start.len())))
+                .max()
+                .and_then(|(i, len)| article[i + len..].split_once("</").map(|(a, _)| a));
+            Article {
+                example: example.map(|block| decode(&strip_tags(block))),
+                answer: answer.map(|answer| decode(&strip_tags(answer))),
+            }
+        })
+        .collect()
+}
+
+/// Find the example inputs in the HTML of a puzzle page. The first `<pre><code>` block of
+/// each part's description is taken as its example, skipping examples that repeat the one
+/// before.
+pub fn examples(html: &str) -> Vec<String> {
+    let mut examples: Vec<String> = Vec::new();
+    for example in articles(html).into_iter().filter_map(|a| a.example) {
+        if examples.last() != Some(&example) {
+            examples.push(example);
+        }
+    }
+    examples
+}
+
+/// Find the expected answer to each part's example, along with the sample input it is for.
+/// A part without an example of its own uses the example of the part before.
+pub fn answers(html: &str) -> Vec<(InputKind, Part, String)> {
+    let samples = [InputKind::Sample, InputKind::Sample2];
+    let mut answers = Vec::new();
+    let (mut previous, mut index) = (None, None::<usize>);
+    for (article, part) in articles(html).into_iter().zip([Part::One, Part::Two]) {
+        if article.example.is_some() && article.example != previous {
+            index = Some(index.map_or(0, |i| i + 1));
+            previous = article.example;
+        }
+        let input = index.and_then(|i| samples.get(i));
+        if let (Some(input), Some(answer)) = (input, article.answer) {
+            answers.push((input.clone(), part, answer));
+        }
+    }
+    answers
+}
+
+/// Save the examples of the puzzle page as `input.sample`, `input.sample2` and so on in
+/// `dir`, and add their expected answers to its `answers.toml`. Examples and answers that
+/// were already saved are kept as they are. Returns the files that were written.
+pub fn save(html: &str, dir: &Path) -> Result<Vec<PathBuf>, String> {
+    fs::create_dir_all(dir).map_err(|err| format!("{}: {err}", dir.display()))?;
+    let mut written = Vec::new();
+    for (i, example) in examples(html).into_iter().enumerate() {
+        let path = match i {
+            0 => dir.join("input.sample"),
+            i => dir.join(format!("input.sample{}", i + 1)),
+        };
+        if fs::metadata(&path).map_or(true, |metadata| metadata.len() == 0) {
+            fs::write(&path, example).map_err(|err| format!("{}: {err}", path.display()))?;
+            written.push(path);
+        }
+    }
+
+    let path = dir.join("answers.toml");
+    let mut recorded = Answers::read(&path).map_err(|err| err.to_string())?;
+    let mut changed = false;
+    for (input, part, answer) in answers(html) {
+        if recorded.get(&input, part).is_none() {
+            recorded.insert(input, part, answer);
+            changed = true;
+        }
+    }
+    if changed {
+        recorded.write(&path).map_err(|err| err.to_string())?;
+        written.push(path);
+    }
+    Ok(written)
+}
+
+fn between<'a>(text: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let (_, rest) = text.split_once(start)?;
+    rest.split_once(end).map(|(inner, _)| inner)
+}
+
+/// Remove any tags such as `<em>` that are used for highlighting.
+fn strip_tags(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut in_tag = false;
+    for ch in html.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            ch if !in_tag => text.push(ch),
+            _ => {}
+        }
+    }
+    text
+}
+
+/// Decode the HTML entities that appear in puzzle text.
+fn decode(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 1 ---</h2>
+<p>For example:</p>
+<pre><code>L68
+<em>R&lt;4&gt;</em>
+</code></pre>
+<pre><code>not the example</code></pre>
+<p>After <code><em>1</em></code> rotation, the password is <code><em>3</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>1165</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>L68
+<em>R&lt;4&gt;</em>
+</code></pre>
+<p>The new password is <em><code>6</code></em>.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn extract_examples() {
+        assert_eq!(vec!["L68\nR<4>\n"], examples(PAGE));
+        let second = "<article><pre><code>a &amp; b</code></pre></article>";
+        assert_eq!(
+            vec!["L68\nR<4>\n", "a & b"],
+            examples(&format!("{PAGE}{second}"))
+        );
+        assert!(examples("<p>No articles</p>").is_empty());
+    }
+
+    #[test]
+    fn extract_answers() {
+        assert_eq!(
+            vec![
+                (InputKind::Sample, Part::One, "3".to_string()),
+                (InputKind::Sample, Part::Two, "6".to_string()),
+            ],
+            answers(PAGE)
+        );
+
+        let page = "<article><pre><code>a</code></pre><code><em>1</em></code></article>\
+                    <article><pre><code>b</code></pre><em><code>2</code></em></article>";
+        assert_eq!(
+            vec![
+                (InputKind::Sample, Part::One, "1".to_string()),
+                (InputKind::Sample2, Part::Two, "2".to_string()),
+            ],
+            answers(page)
+        );
+    }
+
+    #[test]
+    fn save_examples_and_answers() {
+        let dir = std::env::temp_dir().join(format!("aoc-extract-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("answers.toml"), "[sample]\npart1 = 4\n").unwrap();
+
+        assert_eq!(2, save(PAGE, &dir).unwrap().len());
+        assert_eq!(
+            "[sample]\npart1 = 4\npart2 = 6\n",
+            fs::read_to_string(dir.join("answers.toml")).unwrap()
+        );
+        assert!(save(PAGE, &dir).unwrap().is_empty());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
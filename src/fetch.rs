@@ -0,0 +1,402 @@
+use crate::submit::Ledger;
+use crate::{extract, Day, Part, Year};
+use std::fmt::Display;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// Environment variable holding the Advent of Code session cookie.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Environment variable that overrides [`BASE_URL`], e.g. to point at a local server.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "advent-of-code fetch by GeistInDerSH@gmail.com";
+
+/// Something that can talk to Advent of Code.
+pub trait Client {
+    /// Get the body of the page at the path, e.g. `/2025/day/1/input`.
+    fn get(&self, path: &str) -> Result<String, String>;
+
+    /// Post the form to the path, and get the body of the response.
+    fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, String>;
+}
+
+/// A [`Client`] that downloads with `curl`, authenticated with the session cookie.
+pub struct Curl {
+    base_url: String,
+    session: String,
+}
+
+impl Curl {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+        }
+    }
+
+    /// A client for [`BASE_URL`], or for the URL in [`BASE_URL_VAR`] when it is set.
+    pub fn from_env(session: &str) -> Self {
+        let base_url = std::env::var(BASE_URL_VAR).unwrap_or(BASE_URL.to_string());
+        Self::new(&base_url, session)
+    }
+}
+
+impl Curl {
+    fn request(&self, path: &str, args: &[String]) -> Result<String, String> {
+        let url = format!("{}{path}", self.base_url);
+        // The cookie is passed on stdin, so that it does not show up in the process list
+        let mut child = Command::new("curl")
+            .args([
+                "--silent",
+                "--show-error",
+                "--fail",
+                "--location",
+                "--user-agent",
+                USER_AGENT,
+            ])
+            .args(args)
+            .args(["--header", "@-", &url])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|err| format!("Could not run curl: {err}"))?;
+        if let Some(mut stdin) = child.stdin.take() {
+            writeln!(stdin, "Cookie: session={}", self.session)
+                .map_err(|err| format!("Could not run curl: {err}"))?;
+        }
+
+        let output = child
+            .wait_with_output()
+            .map_err(|err| format!("Could not run curl: {err}"))?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(format!("Could not download {url}: {}", stderr.trim()));
+        }
+        String::from_utf8(output.stdout).map_err(|_| format!("{url} is not valid UTF-8"))
+    }
+}
+
+impl Client for Curl {
+    fn get(&self, path: &str) -> Result<String, String> {
+        self.request(path, &[])
+    }
+
+    fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, String> {
+        let args = form
+            .iter()
+            .flat_map(|(key, value)| ["--data-urlencode".to_string(), format!("{key}={value}")])
+            .collect::<Vec<_>>();
+        self.request(path, &args)
+    }
+}
+
+/// What happened to a single input file.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    Downloaded,
+    /// The file already existed, so nothing was downloaded.
+    Cached,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Downloaded => f.pad("downloaded"),
+            Status::Cached => f.pad("cached"),
+        }
+    }
+}
+
+/// Download the real input and the examples of the day into `dir`, which is usually
+/// [`day_dir`](crate::day_dir), along with the expected answers to the examples. Files that
+/// already exist are never downloaded again, but empty files, such as those made by
+/// `new-day`, are filled in. The second example only appears once part 1 is solved, so the
+/// puzzle page is fetched again for it when the [`Ledger`] shows part 1 was accepted, or
+/// when `refresh` is set.
+pub fn fetch(
+    client: &dyn Client,
+    year: Year,
+    day: Day,
+    dir: &Path,
+    refresh: bool,
+) -> Result<Vec<(PathBuf, Status)>, String> {
+    fs::create_dir_all(dir).map_err(|err| format!("{}: {err}", dir.display()))?;
+    let mut fetched = Vec::new();
+
+    let inputs = [dir.join("input.part1"), dir.join("input.part2")];
+    if inputs.iter().any(|path| is_missing(path)) {
+        let input = client.get(&format!("/{year}/day/{day}/input"))?;
+        for path in inputs {
+            if is_missing(&path) {
+                write(&path, &input)?;
+                fetched.push((path, Status::Downloaded));
+            } else {
+                fetched.push((path, Status::Cached));
+            }
+        }
+    } else {
+        fetched.extend(inputs.map(|path| (path, Status::Cached)));
+    }
+
+    let samples = [dir.join("input.sample"), dir.join("input.sample2")];
+    let second_example =
+        is_missing(&samples[1]) && (refresh || Ledger::load(dir)?.is_solved(Part::One));
+    if is_missing(&samples[0]) || second_example {
+        let page = client.get(&format!("/{year}/day/{day}"))?;
+        let saved = extract::save(&page, dir)?;
+        let cached = samples
+            .into_iter()
+            .filter(|path| !is_missing(path) && !saved.contains(path));
+        fetched.extend(cached.map(|path| (path, Status::Cached)));
+        fetched.extend(saved.into_iter().map(|path| (path, Status::Downloaded)));
+    } else {
+        fetched.extend(samples.map(|path| (path, Status::Cached)));
+    }
+    Ok(fetched)
+}
+
+fn is_missing(path: &Path) -> bool {
+    fs::metadata(path).map_or(true, |metadata| metadata.len() == 0)
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+    use std::io::{BufRead, BufReader, Read};
+    use std::net::{TcpListener, TcpStream};
+    use std::sync::{Mutex, OnceLock};
+    use std::thread;
+
+    static PAGES: Mutex<Vec<(String, String)>> = Mutex::new(Vec::new());
+    static REQUESTS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    /// Serve the page from an HTTP server on 127.0.0.1, and return the URL of the server.
+    /// Requests are matched by their method and path, followed by the form for posts, such
+    /// as `POST /2025/day/1/answer level=1&answer=5`. Requests without the `secret` session
+    /// cookie get a 400, and ones without a page a 404. Every test shares the one server.
+    pub(crate) fn serve(request: &str, page: &str) -> String {
+        static SERVER: OnceLock<String> = OnceLock::new();
+        let url = SERVER.get_or_init(|| {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            thread::spawn(move || {
+                for stream in listener.incoming().flatten() {
+                    respond(stream);
+                }
+            });
+            url
+        });
+        PAGES
+            .lock()
+            .unwrap()
+            .push((request.to_string(), page.to_string()));
+        url.clone()
+    }
+
+    /// The requests the server has received that start with the prefix, in order.
+    pub(crate) fn requests(prefix: &str) -> Vec<String> {
+        let requests = REQUESTS.lock().unwrap();
+        let matching = requests
+            .iter()
+            .filter(|request| request.starts_with(prefix));
+        matching.cloned().collect()
+    }
+
+    fn respond(mut stream: TcpStream) {
+        let mut reader = BufReader::new(&stream);
+        let mut request = String::new();
+        reader.read_line(&mut request).unwrap();
+        let (mut length, mut cookie) = (0, String::new());
+        loop {
+            let mut header = String::new();
+            reader.read_line(&mut header).unwrap();
+            let Some((name, value)) = header.trim_end().split_once(": ") else {
+                break;
+            };
+            match name.to_ascii_lowercase().as_str() {
+                "content-length" => length = value.parse().unwrap(),
+                "cookie" => cookie = value.to_string(),
+                _ => {}
+            }
+        }
+        let mut form = vec![0; length];
+        reader.read_exact(&mut form).unwrap();
+
+        let mut request = request
+            .split_whitespace()
+            .take(2)
+            .collect::<Vec<_>>()
+            .join(" ");
+        if !form.is_empty() {
+            request = format!("{request} {}", String::from_utf8_lossy(&form));
+        }
+        REQUESTS.lock().unwrap().push(request.clone());
+        let page = PAGES
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|(served, _)| *served == request)
+            .map(|(_, page)| page.clone());
+        let (status, page) = match page {
+            _ if cookie != "session=secret" => (
+                "400 Bad Request",
+                "Puzzle inputs differ by user.".to_string(),
+            ),
+            Some(page) => ("200 OK", page),
+            None => ("404 Not Found", "404 Not Found".to_string()),
+        };
+        write!(
+            stream,
+            "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{page}",
+            page.len()
+        )
+        .unwrap();
+    }
+
+    /// Serves fixed pages, and records every request made.
+    struct StandIn {
+        pages: HashMap<&'static str, &'static str>,
+        requests: RefCell<Vec<String>>,
+    }
+
+    impl Client for StandIn {
+        fn get(&self, path: &str) -> Result<String, String> {
+            self.requests.borrow_mut().push(path.to_string());
+            self.pages
+                .get(path)
+                .map(ToString::to_string)
+                .ok_or_else(|| format!("404 Not Found: {path}"))
+        }
+
+        fn post(&self, path: &str, _: &[(&str, &str)]) -> Result<String, String> {
+            Err(format!("405 Method Not Allowed: {path}"))
+        }
+    }
+
+    #[test]
+    fn fetch_once() {
+        let client = StandIn {
+            pages: HashMap::from([
+                ("/2025/day/1/input", "R1\nL2\n"),
+                (
+                    "/2025/day/1",
+                    "<article><pre><code>L68\n</code></pre></article>\
+                     <article><pre><code>R14\n</code></pre><code><em>7</em></code></article>",
+                ),
+            ]),
+            requests: RefCell::new(Vec::new()),
+        };
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("input.part2"), "").unwrap();
+
+        let fetched = fetch(&client, 2025, 1, &dir, false).unwrap();
+        assert!(fetched
+            .iter()
+            .all(|(_, status)| *status == Status::Downloaded));
+        assert_eq!(5, fetched.len());
+        assert_eq!(
+            "R1\nL2\n",
+            fs::read_to_string(dir.join("input.part2")).unwrap()
+        );
+        assert_eq!(
+            "R14\n",
+            fs::read_to_string(dir.join("input.sample2")).unwrap()
+        );
+        assert_eq!(
+            "[sample2]\npart2 = 7\n",
+            fs::read_to_string(dir.join("answers.toml")).unwrap()
+        );
+
+        let fetched = fetch(&client, 2025, 1, &dir, false).unwrap();
+        assert!(fetched.iter().all(|(_, status)| *status == Status::Cached));
+        assert_eq!(2, client.requests.borrow().len());
+
+        assert!(fetch(&client, 2025, 2, &dir.join("2"), false).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn fetch_second_example_later() {
+        let stand_in = |page| StandIn {
+            pages: HashMap::from([("/2025/day/2/input", "1-2\n"), ("/2025/day/2", page)]),
+            requests: RefCell::new(Vec::new()),
+        };
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-later-{}", std::process::id()));
+
+        let part1 = stand_in("<article><pre><code>L68\n</code></pre></article>");
+        fetch(&part1, 2025, 2, &dir, false).unwrap();
+        fs::write(dir.join("submissions.tsv"), "1\ttoo_high\t9\n").unwrap();
+        let fetched = fetch(&part1, 2025, 2, &dir, false).unwrap();
+        assert!(fetched.iter().all(|(_, status)| *status == Status::Cached));
+        assert_eq!(2, part1.requests.borrow().len());
+
+        fetch(&part1, 2025, 2, &dir, true).unwrap();
+        assert_eq!(3, part1.requests.borrow().len());
+        assert!(is_missing(&dir.join("input.sample2")));
+
+        fs::write(
+            dir.join("submissions.tsv"),
+            "1\ttoo_high\t9\n1\taccepted\t5\n",
+        )
+        .unwrap();
+
+        let part2 = stand_in(
+            "<article><pre><code>L68\n</code></pre></article>\
+             <article><pre><code>R14\n</code></pre></article>",
+        );
+        let fetched = fetch(&part2, 2025, 2, &dir, false).unwrap();
+        assert!(fetched.contains(&(dir.join("input.sample"), Status::Cached)));
+        assert!(fetched.contains(&(dir.join("input.sample2"), Status::Downloaded)));
+        assert_eq!(
+            "R14\n",
+            fs::read_to_string(dir.join("input.sample2")).unwrap()
+        );
+        fetch(&part2, 2025, 2, &dir, false).unwrap();
+        assert_eq!(1, part2.requests.borrow().len());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn fetch_with_curl() {
+        serve("GET /2025/day/3/input", "L5\n");
+        let url = serve(
+            "GET /2025/day/3",
+            "<article><pre><code>R9\n</code></pre></article>",
+        );
+        let client = Curl::new(&url, "secret\n");
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-curl-{}", std::process::id()));
+
+        fetch(&client, 2025, 3, &dir, false).unwrap();
+        assert_eq!("L5\n", fs::read_to_string(dir.join("input.part1")).unwrap());
+        assert_eq!(
+            "R9\n",
+            fs::read_to_string(dir.join("input.sample")).unwrap()
+        );
+        assert_eq!(
+            vec!["GET /2025/day/3/input", "GET /2025/day/3"],
+            requests("GET /2025/day/3")
+        );
+
+        let err = fetch(&client, 2025, 4, &dir.join("4"), false).unwrap_err();
+        assert!(
+            err.contains("/2025/day/4/input") && err.contains("404"),
+            "{err}"
+        );
+        let err = Curl::new(&url, "stale")
+            .get("/2025/day/3/input")
+            .unwrap_err();
+        assert!(err.contains("400"), "{err}");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
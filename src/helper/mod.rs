@@ -1 +1,2 @@
 pub mod parsing;
+pub mod range_set;
@@ -1,9 +1,462 @@
-pub fn line_to_range_tuple<S, N>(line: S) -> (N, N)
+//! Small parser combinators for puzzle inputs. A parser takes a [`Cursor`] and returns the
+//! parsed value along with the cursor after it, so formats can be described by combining
+//! them, such as `separated(int(), ",")` for `1,2,3`. Failures carry the line and column
+//! they happened at.
+
+use crate::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// The part of the input that is still to be parsed, and where it starts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cursor<'a> {
+    rest: &'a str,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Cursor<'a> {
+    /// Start parsing `text`, which is at the zero-based `line` of the input.
+    pub fn new(text: &'a str, line: usize) -> Self {
+        Self {
+            rest: text,
+            line,
+            column: 0,
+        }
+    }
+
+    #[allow(unused)]
+    pub fn rest(&self) -> &'a str {
+        self.rest
+    }
+
+    /// Move past the first `len` bytes.
+    fn advance(self, len: usize) -> Self {
+        let (taken, rest) = self.rest.split_at(len);
+        let (line, column) = match taken.rfind('\n') {
+            Some(i) => (
+                self.line + taken.matches('\n').count(),
+                taken[i + 1..].chars().count(),
+            ),
+            None => (self.line, self.column + taken.chars().count()),
+        };
+        Self { rest, line, column }
+    }
+
+    /// Fail at this position.
+    pub fn error<T>(&self, reason: impl Into<String>) -> Result<T, ParseError> {
+        Err(ParseError {
+            line: self.line,
+            column: self.column,
+            reason: reason.into(),
+        })
+    }
+
+    /// What is next in the input, for use in error messages.
+    fn next_token(&self) -> &'a str {
+        let end = self
+            .rest
+            .find(char::is_whitespace)
+            .unwrap_or(self.rest.len());
+        match &self.rest[..end] {
+            "" if self.rest.is_empty() => "the end of the input",
+            "" => "whitespace",
+            token => token,
+        }
+    }
+}
+
+/// Why parsing failed, and the zero-based line and column it failed at.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub reason: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Line {}, column {}: {}",
+            self.line + 1,
+            self.column + 1,
+            self.reason
+        )
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(value: ParseError) -> Self {
+        Error::invalid_input(value.line, value.column, value.reason)
+    }
+}
+
+pub type ParseResult<'a, T> = Result<(T, Cursor<'a>), ParseError>;
+
+/// Anything that can parse a `T` from the start of a [`Cursor`].
+pub trait Parser<'a, T>: Fn(Cursor<'a>) -> ParseResult<'a, T> {}
+
+impl<'a, T, F> Parser<'a, T> for F where F: Fn(Cursor<'a>) -> ParseResult<'a, T> {}
+
+/// Parse the whole of `text`. Only trailing whitespace may be left over.
+pub fn parse<'a, T>(text: &'a str, parser: impl Parser<'a, T>) -> Result<T, ParseError> {
+    parse_at(0, text, parser)
+}
+
+/// Parse the whole of `text`, which starts at the zero-based line `index` of the input, such
+/// as a single line or a [`Section`](crate::Section).
+pub fn parse_at<'a, T>(
+    index: usize,
+    text: &'a str,
+    parser: impl Parser<'a, T>,
+) -> Result<T, ParseError> {
+    let (value, rest) = parser(Cursor::new(text, index))?;
+    if rest.rest.trim_end().is_empty() {
+        Ok(value)
+    } else {
+        rest.error(format!("Unexpected {:?}", rest.next_token()))
+    }
+}
+
+/// Match the text exactly.
+pub fn tag<'a>(expected: &'static str) -> impl Parser<'a, &'a str> {
+    move |cursor: Cursor<'a>| match cursor.rest.strip_prefix(expected) {
+        Some(_) => Ok((
+            &cursor.rest[..expected.len()],
+            cursor.advance(expected.len()),
+        )),
+        None => cursor.error(format!(
+            "Expected {expected:?}, found {:?}",
+            cursor.next_token()
+        )),
+    }
+}
+
+/// The end of a line, either `\n` or `\r\n`.
+pub fn line_end<'a>() -> impl Parser<'a, ()> {
+    move |cursor: Cursor<'a>| match line_ending(cursor.rest) {
+        0 => cursor.error(format!(
+            "Expected the end of the line, found {:?}",
+            cursor.next_token()
+        )),
+        len => Ok(((), cursor.advance(len))),
+    }
+}
+
+/// The longest run of characters matching the predicate, which may be empty.
+pub fn take_while<'a>(predicate: impl Fn(char) -> bool) -> impl Parser<'a, &'a str> {
+    move |cursor: Cursor<'a>| {
+        let len = cursor
+            .rest
+            .find(|ch| !predicate(ch))
+            .unwrap_or(cursor.rest.len());
+        Ok((&cursor.rest[..len], cursor.advance(len)))
+    }
+}
+
+/// A non-empty run of letters and digits, such as a name.
+pub fn word<'a>() -> impl Parser<'a, &'a str> {
+    move |cursor: Cursor<'a>| {
+        let (word, rest) = take_while(char::is_alphanumeric)(cursor)?;
+        if word.is_empty() {
+            return cursor.error(format!("Expected a word, found {:?}", cursor.next_token()));
+        }
+        Ok((word, rest))
+    }
+}
+
+/// An integer, with an optional leading `-`.
+pub fn int<'a, N>() -> impl Parser<'a, N>
+where
+    N: FromStr,
+{
+    move |cursor: Cursor<'a>| {
+        let sign = usize::from(cursor.rest.starts_with('-'));
+        let digits = cursor.rest[sign..]
+            .find(|ch: char| !ch.is_ascii_digit())
+            .unwrap_or(cursor.rest.len() - sign);
+        let text = &cursor.rest[..sign + digits];
+        match text.parse() {
+            Ok(value) if digits > 0 => Ok((value, cursor.advance(text.len()))),
+            Ok(_) => cursor.error(format!(
+                "Expected a number, found {:?}",
+                cursor.next_token()
+            )),
+            Err(_) if digits > 0 => cursor.error(format!("{text:?} is out of range")),
+            Err(_) => cursor.error(format!(
+                "Expected a number, found {:?}",
+                cursor.next_token()
+            )),
+        }
+    }
+}
+
+/// A range in the form `a-b`, as the tuple `(a, b)`.
+pub fn range<'a, N>() -> impl Parser<'a, (N, N)>
 where
-    S: AsRef<str>,
-    N: num_traits::Num + std::str::FromStr,
-    <N as std::str::FromStr>::Err: std::fmt::Debug,
+    N: FromStr,
 {
-    let (lhs, rhs) = line.as_ref().split_once('-').unwrap();
-    (lhs.parse().unwrap(), rhs.parse().unwrap())
+    pair(int(), preceded("-", int()))
+}
+
+pub fn map<'a, T, U>(parser: impl Parser<'a, T>, f: impl Fn(T) -> U) -> impl Parser<'a, U> {
+    move |cursor: Cursor<'a>| parser(cursor).map(|(value, rest)| (f(value), rest))
+}
+
+/// Like [`map`], but the conversion can fail, with the error placed where the value started.
+pub fn try_map<'a, T, U>(
+    parser: impl Parser<'a, T>,
+    f: impl Fn(T) -> Result<U, String>,
+) -> impl Parser<'a, U> {
+    move |cursor: Cursor<'a>| {
+        let (value, rest) = parser(cursor)?;
+        match f(value) {
+            Ok(value) => Ok((value, rest)),
+            Err(reason) => cursor.error(reason),
+        }
+    }
+}
+
+pub fn pair<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    move |cursor: Cursor<'a>| {
+        let (a, cursor) = first(cursor)?;
+        let (b, cursor) = second(cursor)?;
+        Ok(((a, b), cursor))
+    }
+}
+
+/// Match `prefix`, then keep only the value of `parser`.
+pub fn preceded<'a, T>(prefix: &'static str, parser: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    move |cursor: Cursor<'a>| parser(tag(prefix)(cursor)?.1)
+}
+
+/// One or more values separated by `separator`, such as `1,2,3`. The list ends at the first
+/// separator that is not followed by a value, which is left unparsed. A value that starts
+/// but does not finish parsing is still an error.
+pub fn separated<'a, T>(
+    parser: impl Parser<'a, T>,
+    separator: &'static str,
+) -> impl Parser<'a, Vec<T>> {
+    move |cursor: Cursor<'a>| {
+        let (first, mut cursor) = parser(cursor)?;
+        let mut values = vec![first];
+        while let Ok((_, next)) = tag(separator)(cursor) {
+            match parser(next) {
+                Ok((value, rest)) => {
+                    values.push(value);
+                    cursor = rest;
+                }
+                Err(err) if (err.line, err.column) == (next.line, next.column) => break,
+                Err(err) => return Err(err),
+            }
+        }
+        Ok((values, cursor))
+    }
+}
+
+/// A value wrapped in `open` and `close`.
+pub fn delimited<'a, T>(
+    open: &'static str,
+    parser: impl Parser<'a, T>,
+    close: &'static str,
+) -> impl Parser<'a, T> {
+    move |cursor: Cursor<'a>| {
+        let (value, cursor) = preceded(open, &parser)(cursor)?;
+        Ok((value, tag(close)(cursor)?.1))
+    }
+}
+
+/// A value wrapped in `[` and `]`.
+pub fn brackets<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    delimited("[", parser, "]")
+}
+
+/// A value wrapped in `(` and `)`.
+pub fn parens<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    delimited("(", parser, ")")
+}
+
+/// A value wrapped in `{` and `}`.
+pub fn braces<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    delimited("{", parser, "}")
+}
+
+/// A key and its value, such as `aaa: bbb ccc`.
+pub fn key_value<'a, K, V>(
+    key: impl Parser<'a, K>,
+    separator: &'static str,
+    value: impl Parser<'a, V>,
+) -> impl Parser<'a, (K, V)> {
+    pair(key, preceded(separator, value))
+}
+
+/// Everything that is left of the input, along with the zero-based line it starts at.
+pub fn remainder<'a>() -> impl Parser<'a, (usize, &'a str)> {
+    |cursor: Cursor<'a>| {
+        let len = cursor.rest.len();
+        Ok(((cursor.line, cursor.rest), cursor.advance(len)))
+    }
+}
+
+/// Run the parser on the text up to the end of the line or input, which it must consume.
+fn whole<'a, T>(parser: &impl Parser<'a, T>, cursor: Cursor<'a>, len: usize) -> ParseResult<'a, T> {
+    let block = Cursor {
+        rest: &cursor.rest[..len],
+        ..cursor
+    };
+    let (value, rest) = parser(block)?;
+    if !rest.rest.is_empty() {
+        return rest.error(format!("Unexpected {:?}", rest.next_token()));
+    }
+    Ok((value, cursor.advance(len)))
+}
+
+/// One value per line, each filling the whole line, up to the next blank line.
+pub fn lines<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |mut cursor: Cursor<'a>| {
+        let mut values = Vec::new();
+        while !cursor.rest.is_empty() && !cursor.rest.starts_with(['\n', '\r']) {
+            let len = cursor.rest.find(['\r', '\n']).unwrap_or(cursor.rest.len());
+            let (value, rest) = whole(&parser, cursor, len)?;
+            values.push(value);
+            cursor = rest.advance(line_ending(rest.rest));
+        }
+        Ok((values, cursor))
+    }
+}
+
+/// A block of lines up to the next blank line, which the parser must consume. Lines holding
+/// only whitespace count as blank, and the blank lines around the block are skipped.
+pub fn section<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, T> {
+    move |cursor: Cursor<'a>| {
+        let cursor = skip_blank_lines(cursor);
+        let len = cursor
+            .rest
+            .split_inclusive('\n')
+            .take_while(|line| !line.trim().is_empty())
+            .map(str::len)
+            .sum();
+        let (value, cursor) = whole(&parser, cursor, len)?;
+        Ok((value, skip_blank_lines(cursor)))
+    }
+}
+
+fn skip_blank_lines(mut cursor: Cursor<'_>) -> Cursor<'_> {
+    while let Some(line) = cursor.rest.split_inclusive('\n').next()
+        && line.trim().is_empty()
+    {
+        cursor = cursor.advance(line.len());
+    }
+    cursor
+}
+
+/// Every remaining blank-line-separated section, parsed the same way.
+pub fn sections<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    let section = section(parser);
+    move |mut cursor: Cursor<'a>| {
+        let mut values = Vec::new();
+        while !cursor.rest.trim_end().is_empty() {
+            let (value, rest) = section(cursor)?;
+            values.push(value);
+            cursor = rest;
+        }
+        Ok((values, cursor))
+    }
+}
+
+fn line_ending(text: &str) -> usize {
+    if text.starts_with("\r\n") {
+        2
+    } else {
+        usize::from(text.starts_with('\n'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn values_and_lists() {
+        assert_eq!(Ok(-12), parse("-12", int::<i32>()));
+        assert_eq!(Ok((3, 5)), parse("3-5\n", range::<u64>()));
+        assert_eq!(
+            Ok(vec![vec![1, 3], vec![2]]),
+            parse(
+                "(1,3) (2)",
+                separated(parens(separated(int::<u8>(), ",")), " ")
+            )
+        );
+        let (lights, rest) =
+            brackets(take_while(|ch| ch == '.' || ch == '#'))(Cursor::new("[.##.] {3}", 0))
+                .unwrap();
+        assert_eq!(".##.", lights);
+        assert_eq!(
+            Ok(vec![3]),
+            parse_at(
+                0,
+                rest.rest(),
+                preceded(" ", braces(separated(int::<u8>(), ",")))
+            )
+        );
+        assert_eq!(
+            Ok(("aaa", vec!["you", "hhh"])),
+            parse(
+                "aaa: you hhh",
+                key_value(word(), ": ", separated(word(), " "))
+            )
+        );
+    }
+
+    #[test]
+    fn positioned_errors() {
+        let err = parse("1,2,x", separated(int::<u8>(), ",")).unwrap_err();
+        assert_eq!((0, 3), (err.line, err.column));
+        assert_eq!("Line 1, column 4: Unexpected \",x\"", err.to_string());
+
+        let err = parse_at(4, "(1,x)", parens(separated(int::<u8>(), ","))).unwrap_err();
+        assert_eq!(
+            "Line 5, column 3: Expected \")\", found \",x)\"",
+            err.to_string()
+        );
+
+        let err = parse("300", int::<u8>()).unwrap_err();
+        assert_eq!("\"300\" is out of range", err.reason);
+        let err = parse("a:", key_value(word(), ": ", word())).unwrap_err();
+        assert_eq!((0, 1), (err.line, err.column));
+
+        assert_eq!(Ok((7, ())), parse("7\r\n", pair(int::<u8>(), line_end())));
+        let err = parse("7:", pair(int::<u8>(), line_end())).unwrap_err();
+        assert_eq!((0, 1), (err.line, err.column));
+    }
+
+    #[test]
+    fn lines_and_sections() {
+        let text = "1-2\n3-4\n\n5\n6\n";
+        let parser = pair(section(lines(range::<u8>())), section(lines(int::<u8>())));
+        assert_eq!(Ok((vec![(1, 2), (3, 4)], vec![5, 6])), parse(text, parser));
+
+        let blocks = parse("1\n2\n\n\n3\n", sections(lines(int::<u8>())));
+        assert_eq!(Ok(vec![vec![1, 2], vec![3]]), blocks);
+
+        let err = parse("1\n2\n\n3\nx\n", sections(lines(int::<u8>()))).unwrap_err();
+        assert_eq!((4, 0), (err.line, err.column));
+    }
+
+    #[test]
+    fn sections_with_crlf() {
+        let text = "1-2\r\n3-4\r\n\r\n  \r\n5\r\n6\r\n";
+        let parser = pair(section(lines(range::<u8>())), section(lines(int::<u8>())));
+        assert_eq!(Ok((vec![(1, 2), (3, 4)], vec![5, 6])), parse(text, parser));
+
+        let blocks = parse("\r\n1\r\n\r\n2\n\n3", sections(lines(int::<u8>())));
+        assert_eq!(Ok(vec![vec![1], vec![2], vec![3]]), blocks);
+
+        let err = parse("1\r\n\r\nx\r\n", sections(lines(int::<u8>()))).unwrap_err();
+        assert_eq!((2, 0), (err.line, err.column));
+    }
 }
@@ -0,0 +1,187 @@
+use std::ops::RangeInclusive;
+
+/// A set of integers, stored as sorted inclusive ranges. Ranges that overlap or touch are
+/// merged as they are inserted, so no two ranges in the set ever overlap.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RangeSet<N> {
+    ranges: Vec<(N, N)>,
+}
+
+#[allow(unused)]
+impl<N> RangeSet<N>
+where
+    N: num_traits::PrimInt,
+{
+    pub fn new() -> Self {
+        Self { ranges: Vec::new() }
+    }
+
+    /// Add every value from `start` to `end` inclusive. Nothing is added if `start > end`.
+    pub fn insert(&mut self, start: N, end: N) {
+        if start > end {
+            return;
+        }
+
+        // Ranges that end just before `start` still touch it, and so are merged
+        let first = self
+            .ranges
+            .partition_point(|&(_, e)| e.saturating_add(N::one()) < start);
+        let (mut start, mut end) = (start, end);
+        let mut last = first;
+        while let Some(&(s, e)) = self.ranges.get(last)
+            && s <= end.saturating_add(N::one())
+        {
+            start = start.min(s);
+            end = end.max(e);
+            last += 1;
+        }
+        self.ranges.splice(first..last, [(start, end)]);
+    }
+
+    pub fn contains(&self, value: N) -> bool {
+        let index = self.ranges.partition_point(|&(_, e)| e < value);
+        self.ranges.get(index).is_some_and(|&(s, _)| s <= value)
+    }
+
+    /// The values in either set.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for &(start, end) in &other.ranges {
+            union.insert(start, end);
+        }
+        union
+    }
+
+    /// The values in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        let (mut a, mut b) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+        while let (Some(&&(a_start, a_end)), Some(&&(b_start, b_end))) = (a.peek(), b.peek()) {
+            let (start, end) = (a_start.max(b_start), a_end.min(b_end));
+            if start <= end {
+                ranges.push((start, end));
+            }
+            if a_end < b_end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        Self { ranges }
+    }
+
+    /// The values in this set that are not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = Vec::new();
+        for &(start, end) in &self.ranges {
+            let mut next = Some(start);
+            for &(o_start, o_end) in other
+                .ranges
+                .iter()
+                .filter(|&&(s, e)| s <= end && e >= start)
+            {
+                let Some(from) = next else {
+                    break;
+                };
+                if o_start > from {
+                    ranges.push((from, o_start - N::one()));
+                }
+                next = o_end.checked_add(&N::one());
+            }
+            if let Some(from) = next
+                && from <= end
+            {
+                ranges.push((from, end));
+            }
+        }
+        Self { ranges }
+    }
+
+    /// The number of values in the set.
+    pub fn len(&self) -> N {
+        self.ranges.iter().fold(N::zero(), |len, &(start, end)| {
+            len + (end - start) + N::one()
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The ranges of the set, in order.
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<N>> + '_ {
+        self.ranges.iter().map(|&(start, end)| start..=end)
+    }
+}
+
+impl<N> FromIterator<(N, N)> for RangeSet<N>
+where
+    N: num_traits::PrimInt,
+{
+    fn from_iter<I: IntoIterator<Item = (N, N)>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for (start, end) in iter {
+            set.insert(start, end);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges(set: &RangeSet<i32>) -> Vec<(i32, i32)> {
+        set.iter()
+            .map(|range| (*range.start(), *range.end()))
+            .collect()
+    }
+
+    #[test]
+    fn insert_and_merge() {
+        let mut set = [(10, 14), (3, 5), (16, 20)]
+            .into_iter()
+            .collect::<RangeSet<i32>>();
+        assert_eq!(vec![(3, 5), (10, 14), (16, 20)], ranges(&set));
+
+        // Widening one range must also swallow the ranges it now reaches
+        set.insert(12, 18);
+        assert_eq!(vec![(3, 5), (10, 20)], ranges(&set));
+        set.insert(6, 9);
+        assert_eq!(vec![(3, 20)], ranges(&set));
+        set.insert(30, 29);
+        assert_eq!(18, set.len());
+
+        assert!(set.contains(3));
+        assert!(set.contains(20));
+        assert!(!set.contains(2));
+        assert!(!set.contains(21));
+        assert!(RangeSet::<i32>::new().is_empty());
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = [(0, 10), (20, 30)].into_iter().collect::<RangeSet<i32>>();
+        let b = [(5, 25), (30, 40)].into_iter().collect::<RangeSet<i32>>();
+        assert_eq!(vec![(0, 40)], ranges(&a.union(&b)));
+        assert_eq!(
+            vec![(5, 10), (20, 25), (30, 30)],
+            ranges(&a.intersection(&b))
+        );
+        assert_eq!(vec![(0, 4), (26, 29)], ranges(&a.difference(&b)));
+        assert_eq!(vec![(11, 19), (31, 40)], ranges(&b.difference(&a)));
+        assert!(a.difference(&a).is_empty());
+
+        let everything = [(i32::MIN, i32::MAX)]
+            .into_iter()
+            .collect::<RangeSet<i32>>();
+        assert!(a.difference(&everything).is_empty());
+        assert_eq!(
+            vec![(i32::MIN, -1), (11, 19), (31, i32::MAX)],
+            ranges(&everything.difference(&a))
+        );
+    }
+}
@@ -1,21 +1,33 @@
-pub mod day_1;
-pub mod day_10;
-pub mod day_11;
-pub mod day_12;
-pub mod day_2;
-pub mod day_3;
-pub mod day_4;
-pub mod day_5;
-pub mod day_6;
-pub mod day_7;
-pub mod day_8;
-pub mod day_9;
+pub mod answers;
+pub mod cli;
+mod error;
+pub mod extract;
+pub mod fetch;
 mod helper;
+pub mod output;
 mod points;
+pub mod registry;
+pub mod runner;
+pub mod scaffold;
+pub mod submit;
+pub mod verify;
+pub mod watch;
+pub mod year_2025;
 
+pub use error::Error;
+
+use helper::parsing::{parse, remainder, sections};
 use std::fmt::Display;
 use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::{BufRead, BufReader, ErrorKind, Read};
+use std::marker::PhantomData;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+/// Environment variable that overrides the directory the puzzle inputs are read from.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+static INPUT_DIR: OnceLock<PathBuf> = OnceLock::new();
 
 pub trait Solution<P1, P2>
 where
@@ -25,35 +37,299 @@ where
     fn part1(&self) -> P1;
     fn part2(&self) -> P2;
 
-    fn report(&self, day: u8) {
-        println!("Day {day}");
-        println!("Part 1: {}", self.part1());
-        println!("Part 2: {}", self.part2());
+    /// A drawing of how the puzzle was solved, for days where there is something to see.
+    fn visualize(&self) -> Option<String> {
+        None
+    }
+}
+
+/// An object safe version of [`Solution`] with the answers erased to [`Display`], so
+/// that days with different answer types can be stored and run together, including on
+/// separate threads.
+pub trait DynSolution: Send + Sync {
+    fn part1(&self) -> Box<dyn Display>;
+    fn part2(&self) -> Box<dyn Display>;
+    fn visualize(&self) -> Option<String>;
+}
+
+struct Erased<S, P1, P2>(S, PhantomData<fn() -> (P1, P2)>);
+
+impl<S, P1, P2> DynSolution for Erased<S, P1, P2>
+where
+    S: Solution<P1, P2> + Send + Sync,
+    P1: Display + 'static,
+    P2: Display + 'static,
+{
+    fn part1(&self) -> Box<dyn Display> {
+        Box::new(self.0.part1())
+    }
+
+    fn part2(&self) -> Box<dyn Display> {
+        Box::new(self.0.part2())
+    }
+
+    fn visualize(&self) -> Option<String> {
+        self.0.visualize()
+    }
+}
+
+/// Erase the answer types of the [`Solution`] so it can be used as a [`DynSolution`].
+pub fn erase<S, P1, P2>(solution: S) -> Box<dyn DynSolution>
+where
+    S: Solution<P1, P2> + Send + Sync + 'static,
+    P1: Display + 'static,
+    P2: Display + 'static,
+{
+    Box::new(Erased(solution, PhantomData))
+}
+
+pub type Year = u16;
+pub type Day = u8;
+
+/// Which part(s) of a day should be solved.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+    Both,
+}
+
+impl Part {
+    /// Check if this selection covers the given [`Part`].
+    pub fn includes(self, other: Part) -> bool {
+        self == Part::Both || self == other
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+            Part::Both => write!(f, "both"),
+        }
+    }
+}
+
+/// The kind of input to load, without the day attached.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum InputKind {
+    Sample,
+    Sample2,
+    Part1,
+    Part2,
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputKind {
+    pub fn with_day(&self, year: Year, day: Day) -> Input {
+        match self {
+            InputKind::Sample => Input::Sample(year, day),
+            InputKind::Sample2 => Input::Sample2(year, day),
+            InputKind::Part1 => Input::Part1(year, day),
+            InputKind::Part2 => Input::Part2(year, day),
+            InputKind::Path(path) => Input::Path(path.clone()),
+            InputKind::Stdin => Input::Stdin,
+        }
+    }
+}
+
+impl std::str::FromStr for InputKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sample" => Ok(InputKind::Sample),
+            "sample2" => Ok(InputKind::Sample2),
+            "part1" => Ok(InputKind::Part1),
+            "part2" => Ok(InputKind::Part2),
+            "-" | "stdin" => Ok(InputKind::Stdin),
+            "" => Err("Input must not be empty".to_string()),
+            path => Ok(InputKind::Path(PathBuf::from(path))),
+        }
     }
 }
 
-type Day = u8;
+impl Display for InputKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputKind::Sample => write!(f, "sample"),
+            InputKind::Sample2 => write!(f, "sample2"),
+            InputKind::Part1 => write!(f, "part1"),
+            InputKind::Part2 => write!(f, "part2"),
+            InputKind::Path(path) => write!(f, "{}", path.display()),
+            InputKind::Stdin => write!(f, "stdin"),
+        }
+    }
+}
 
+#[derive(Clone, Debug)]
 pub enum Input {
-    Sample(Day),
-    Sample2(Day),
-    Part1(Day),
-    Part2(Day),
+    Sample(Year, Day),
+    Sample2(Year, Day),
+    Part1(Year, Day),
+    Part2(Year, Day),
+    /// A file anywhere on disk, used as given rather than relative to [`input_dir`].
+    Path(PathBuf),
+    /// Everything written to stdin, until it is closed.
+    Stdin,
+}
+
+impl Input {
+    pub fn kind(&self) -> InputKind {
+        match self {
+            Input::Sample(..) => InputKind::Sample,
+            Input::Sample2(..) => InputKind::Sample2,
+            Input::Part1(..) => InputKind::Part1,
+            Input::Part2(..) => InputKind::Part2,
+            Input::Path(path) => InputKind::Path(path.clone()),
+            Input::Stdin => InputKind::Stdin,
+        }
+    }
+
+    /// The file the input is read from, or [`None`] when it is read from stdin.
+    pub fn path(&self) -> Option<PathBuf> {
+        let (year, day, file) = match self {
+            Input::Sample(year, day) => (year, day, "input.sample"),
+            Input::Sample2(year, day) => (year, day, "input.sample2"),
+            Input::Part1(year, day) => (year, day, "input.part1"),
+            Input::Part2(year, day) => (year, day, "input.part2"),
+            Input::Path(path) => return Some(path.clone()),
+            Input::Stdin => return None,
+        };
+        Some(day_dir(*year, *day).join(file))
+    }
 }
 
 impl Display for Input {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Input::Sample(day) => write!(f, "inputs/{day}/input.sample"),
-            Input::Sample2(day) => write!(f, "inputs/{day}/input.sample2"),
-            Input::Part1(day) => write!(f, "inputs/{day}/input.part1"),
-            Input::Part2(day) => write!(f, "inputs/{day}/input.part2"),
+        match self.path() {
+            Some(path) => write!(f, "{}", path.display()),
+            None => write!(f, "stdin"),
         }
     }
 }
 
-fn read_to_iter(input: &Input) -> Result<impl Iterator<Item = String>, std::io::Error> {
-    let file = File::open(input.to_string())?;
-    let reader = BufReader::new(file);
-    Ok(reader.lines().map(Result::unwrap))
+/// Set the directory the puzzle inputs are read from, taking priority over
+/// [`INPUT_DIR_VAR`]. Only the first call has any effect.
+pub fn set_input_dir(dir: PathBuf) {
+    let _ = INPUT_DIR.set(dir);
+}
+
+/// The directory containing the `{year}/{day}/input.*` files. Defaults to `inputs` in the
+/// current directory.
+pub fn input_dir() -> PathBuf {
+    INPUT_DIR
+        .get()
+        .cloned()
+        .or_else(|| std::env::var_os(INPUT_DIR_VAR).map(PathBuf::from))
+        .unwrap_or_else(|| PathBuf::from("inputs"))
+}
+
+/// The directory holding the inputs and answers of a single day.
+pub fn day_dir(year: Year, day: Day) -> PathBuf {
+    input_dir().join(year.to_string()).join(day.to_string())
+}
+
+fn open(input: &Input) -> Result<Box<dyn Read>, Error> {
+    let Some(path) = input.path() else {
+        return Ok(Box::new(std::io::stdin()));
+    };
+    match File::open(&path) {
+        Ok(file) => Ok(Box::new(file)),
+        Err(err) if err.kind() == ErrorKind::NotFound => {
+            Err(Error::MissingInput(path.display().to_string()))
+        }
+        Err(err) => Err(Error::Io(err)),
+    }
+}
+
+/// Read the input line by line. The whole input is read up front, so any IO errors are
+/// reported here rather than while iterating.
+pub fn read_to_iter(input: &Input) -> Result<impl Iterator<Item = String>, Error> {
+    let reader = BufReader::new(open(input)?);
+    let lines = reader.lines().collect::<Result<Vec<_>, _>>()?;
+    Ok(lines.into_iter())
+}
+
+/// A block of the input between blank lines.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Section {
+    /// The zero-based index of the block's first line in the whole input.
+    pub line: usize,
+    /// The lines of the block, without the final line ending.
+    pub text: String,
+}
+
+impl Section {
+    /// The lines of the block, along with their zero-based index in the whole input.
+    pub fn lines(&self) -> impl Iterator<Item = (usize, &str)> {
+        self.text
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (self.line + i, line))
+    }
+}
+
+/// Split the text into its blank-line-separated blocks, as the `sections` parser does. Any number of
+/// blank lines, including ones holding only whitespace, separate two blocks.
+pub fn split_sections(text: &str) -> impl Iterator<Item = Section> {
+    let blocks = parse(text, sections(remainder())).expect("Any text splits into sections");
+    blocks.into_iter().map(|(line, text)| Section {
+        line,
+        text: text.trim_end_matches(['\r', '\n']).to_string(),
+    })
+}
+
+/// Read the input as its blank-line-separated blocks, for inputs made of several parts.
+pub fn read_sections(input: &Input) -> Result<impl Iterator<Item = Section>, Error> {
+    let text = read_to_string(input)?;
+    Ok(split_sections(&text).collect::<Vec<_>>().into_iter())
+}
+
+/// Read the whole input into a string, ready to be parsed with [`FromStr`](std::str::FromStr).
+pub fn read_to_string(input: &Input) -> Result<String, Error> {
+    let mut contents = String::new();
+    open(input)?.read_to_string(&mut contents)?;
+    Ok(contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_input() {
+        let err = read_to_string(&Input::Part1(2025, 0)).unwrap_err();
+        assert!(matches!(err, Error::MissingInput(path) if path == "inputs/2025/0/input.part1"));
+    }
+
+    #[test]
+    fn sections() {
+        let text = "a\nb\n\n\n  \nc\r\n\r\nd\n";
+        let sections = split_sections(text).collect::<Vec<_>>();
+        assert_eq!(3, sections.len());
+        assert_eq!(
+            vec![(0, "a"), (1, "b")],
+            sections[0].lines().collect::<Vec<_>>()
+        );
+        assert_eq!((5, "c"), (sections[1].line, sections[1].text.as_str()));
+        assert_eq!((7, "d"), (sections[2].line, sections[2].text.as_str()));
+        assert_eq!(0, split_sections("\n\n").count());
+
+        let input = Input::Sample(2025, 5);
+        assert_eq!(2, read_sections(&input).unwrap().count());
+    }
+
+    #[test]
+    fn read_from_path() {
+        let input = Input::Path(PathBuf::from("inputs/2025/1/input.sample"));
+        let lines = read_to_iter(&input).unwrap().collect::<Vec<_>>();
+        assert_eq!(Some("L68"), lines.first().map(String::as_str));
+        assert_eq!(
+            InputKind::Path(PathBuf::from("inputs/2025/1/input.sample")),
+            input.kind()
+        );
+    }
 }
@@ -1,15 +1,202 @@
+use advent_of_code::cli::{
+    self, Command, ExtractArgs, FetchArgs, NewDayArgs, RunArgs, SubmitArgs, VerifyArgs, WatchArgs,
+    USAGE,
+};
+use advent_of_code::extract;
+use advent_of_code::fetch::{self, Curl};
+use advent_of_code::output::{self, Format};
+use advent_of_code::registry::Entry;
+use advent_of_code::submit::{self, Ledger, Verdict};
+use advent_of_code::verify::{self, Status};
+use advent_of_code::{registry, runner, scaffold, watch, Day, Input, Year};
+use std::path::Path;
+use std::time::Duration;
+
 fn main() {
-    println!("Advent of Code 2025");
-    advent_of_code::day_1::run();
-    advent_of_code::day_2::run();
-    advent_of_code::day_3::run();
-    advent_of_code::day_4::run();
-    advent_of_code::day_5::run();
-    advent_of_code::day_6::run();
-    advent_of_code::day_7::run();
-    advent_of_code::day_8::run();
-    advent_of_code::day_9::run();
-    advent_of_code::day_10::run();
-    advent_of_code::day_11::run();
-    advent_of_code::day_12::run();
+    match cli::parse(std::env::args().skip(1)) {
+        Ok(Command::Run(args)) => run(args),
+        Ok(Command::Verify(args)) => verify(args),
+        Ok(Command::NewDay(args)) => new_day(args),
+        Ok(Command::Fetch(args)) => fetch(args),
+        Ok(Command::Submit(args)) => submit(args),
+        Ok(Command::Watch(args)) => watch(args),
+        Ok(Command::Extract(args)) => extract(args),
+        Ok(Command::Help) => println!("{USAGE}"),
+        Err(err) => {
+            eprintln!("{err}\n\n{USAGE}");
+            std::process::exit(2);
+        }
+    }
+}
+
+fn run(args: RunArgs) {
+    if let Some(dir) = args.input_dir {
+        advent_of_code::set_input_dir(dir);
+    }
+    let entries = entries(args.year, &args.days);
+    if matches!(args.format, Format::Text | Format::Visual) {
+        println!("Advent of Code {}", args.year);
+    }
+    let jobs = entries
+        .into_iter()
+        .map(|entry| (entry, args.input.with_day(entry.year, entry.day)))
+        .collect::<Vec<_>>();
+
+    let results = runner::run_all(jobs, args.part, args.jobs, args.format == Format::Visual);
+    for failure in results.iter().filter_map(|result| result.as_ref().err()) {
+        eprintln!("{failure}");
+    }
+    print!("{}", output::render(args.format, &results));
+}
+
+fn verify(args: VerifyArgs) {
+    if let Some(dir) = args.input_dir {
+        advent_of_code::set_input_dir(dir);
+    }
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    for entry in entries(args.year, &args.days) {
+        match verify::verify(entry) {
+            Ok(checks) => {
+                for check in checks {
+                    println!("{check}");
+                    match check.status {
+                        Status::Pass => passed += 1,
+                        Status::Fail { .. } => failed += 1,
+                        Status::Missing(_) => missing += 1,
+                    }
+                }
+            }
+            Err(err) => {
+                eprintln!("Day {}: {err}", entry.day);
+                failed += 1;
+            }
+        }
+    }
+
+    println!("\n{passed} passed, {failed} failed, {missing} missing");
+    if failed > 0 {
+        std::process::exit(1);
+    }
+}
+
+/// Scaffold the day in the crate this binary was built from.
+fn new_day(args: NewDayArgs) {
+    match scaffold::new_day(Path::new(env!("CARGO_MANIFEST_DIR")), args.year, args.day) {
+        Ok(changed) => {
+            for path in changed {
+                println!("{}", path.display());
+            }
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            std::process::exit(1);
+        }
+    }
+}
+
+fn fetch(args: FetchArgs) {
+    if let Some(dir) = args.input_dir {
+        advent_of_code::set_input_dir(dir);
+    }
+    let client = client(args.session);
+
+    let dir = advent_of_code::day_dir(args.year, args.day);
+    match fetch::fetch(&client, args.year, args.day, &dir, args.refresh) {
+        Ok(fetched) => {
+            for (path, status) in fetched {
+                println!("{status:<10} {}", path.display());
+            }
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            std::process::exit(1);
+        }
+    }
+}
+
+fn submit(args: SubmitArgs) {
+    if let Some(dir) = args.input_dir {
+        advent_of_code::set_input_dir(dir);
+    }
+    let entry = entries(args.year, &[args.day])[0];
+    let client = client(args.session);
+
+    let result = runner::run(entry, Input::Part1(args.year, args.day), args.part)
+        .map_err(|err| err.to_string())
+        .and_then(|result| {
+            let answer = &result.parts[0].answer;
+            println!("Day {} / Part {}: {answer}", args.day, args.part);
+            let mut ledger = Ledger::load(&advent_of_code::day_dir(args.year, args.day))?;
+            submit::submit(&client, &mut ledger, args.year, args.day, args.part, answer)
+        });
+    match result {
+        Ok(Verdict::Accepted) => println!("The answer was accepted"),
+        Ok(verdict) => {
+            println!("The answer was {verdict}");
+            std::process::exit(1);
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            std::process::exit(1);
+        }
+    }
+}
+
+fn watch(args: WatchArgs) {
+    if let Some(dir) = args.input_dir {
+        advent_of_code::set_input_dir(dir);
+    }
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    if let Err(err) = watch::watch(root, args.year, args.day, Duration::from_millis(500)) {
+        eprintln!("{err}");
+        std::process::exit(1);
+    }
+}
+
+fn extract(args: ExtractArgs) {
+    if let Some(dir) = args.input_dir {
+        advent_of_code::set_input_dir(dir);
+    }
+    let result = std::fs::read_to_string(&args.html)
+        .map_err(|err| format!("{}: {err}", args.html.display()))
+        .and_then(|html| extract::save(&html, &advent_of_code::day_dir(args.year, args.day)));
+    match result {
+        Ok(written) if written.is_empty() => println!("Nothing new to save"),
+        Ok(written) => {
+            for path in written {
+                println!("{}", path.display());
+            }
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// The registry entries of the days, or exit if any of them is not implemented.
+fn entries(year: Year, days: &[Day]) -> Vec<&'static Entry> {
+    let mut entries = Vec::new();
+    for &day in days {
+        match registry::find(year, day) {
+            Some(entry) => entries.push(entry),
+            None => eprintln!("Day {day} is not implemented"),
+        }
+    }
+    if entries.len() < days.len() {
+        std::process::exit(1);
+    }
+    entries
+}
+
+/// A [`Curl`] client using the session cookie from the command line or environment.
+fn client(session: Option<String>) -> Curl {
+    let Some(session) = session.or_else(|| std::env::var(fetch::SESSION_VAR).ok()) else {
+        eprintln!(
+            "A session cookie is needed, from --session or {}",
+            fetch::SESSION_VAR
+        );
+        std::process::exit(2);
+    };
+    Curl::from_env(&session)
 }
@@ -0,0 +1,234 @@
+use crate::runner::{self, DayFailure, DayResult, PartResult};
+use crate::{Day, Year};
+use std::fmt::Write;
+
+/// How the results of a run are written to stdout.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// Human readable answers, followed by a summary table of the timings.
+    Text,
+    /// The same as [`Format::Text`], with a drawing of the solution after the answers of
+    /// each day that can draw one.
+    Visual,
+    /// A JSON array with one object per solved part, and one with an error per day that
+    /// could not be solved.
+    Json,
+    /// CSV with a header, and one row per solved part or day that could not be solved.
+    Csv,
+}
+
+impl std::str::FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            "visual" => Ok(Format::Visual),
+            _ => Err(format!("Unknown format: {s}")),
+        }
+    }
+}
+
+/// Render all the results in the given [`Format`]. Days that could not be solved are left out
+/// of the text formats, as their errors are reported separately.
+pub fn render(format: Format, results: &[Result<DayResult, DayFailure>]) -> String {
+    match format {
+        Format::Text | Format::Visual => {
+            let results = results
+                .iter()
+                .filter_map(|result| result.as_ref().ok())
+                .collect::<Vec<_>>();
+            let mut text = String::new();
+            for result in &results {
+                let _ = writeln!(text, "{result}");
+                if format == Format::Visual
+                    && let Some(visualization) = &result.visualization
+                {
+                    let _ = writeln!(text, "{visualization}");
+                }
+            }
+            text += &runner::summary(&results);
+            text
+        }
+        Format::Json => {
+            let rows = rows(results)
+                .map(|row| match row.outcome {
+                    Ok(part) => format!(
+                        r#"  {{"year": {}, "day": {}, "part": {}, "input": "{}", "answer": "{}", "elapsed_ns": {}}}"#,
+                        row.year,
+                        row.day,
+                        part.part,
+                        escape_json(&row.input),
+                        escape_json(&part.answer),
+                        part.elapsed.as_nanos()
+                    ),
+                    Err(error) => format!(
+                        r#"  {{"year": {}, "day": {}, "input": "{}", "error": "{}"}}"#,
+                        row.year,
+                        row.day,
+                        escape_json(&row.input),
+                        escape_json(&error)
+                    ),
+                })
+                .collect::<Vec<_>>();
+            if rows.is_empty() {
+                "[]\n".to_string()
+            } else {
+                format!("[\n{}\n]\n", rows.join(",\n"))
+            }
+        }
+        Format::Csv => rows(results).fold(
+            "year,day,part,input,answer,elapsed_ns,error\n".to_string(),
+            |mut csv, row| {
+                let (part, answer, elapsed_ns, error) = match row.outcome {
+                    Ok(part) => (
+                        part.part.to_string(),
+                        part.answer.as_str(),
+                        part.elapsed.as_nanos().to_string(),
+                        String::new(),
+                    ),
+                    Err(error) => (String::new(), "", String::new(), error),
+                };
+                let _ = writeln!(
+                    csv,
+                    "{},{},{},{},{},{},{}",
+                    row.year,
+                    row.day,
+                    part,
+                    escape_csv(&row.input),
+                    escape_csv(answer),
+                    elapsed_ns,
+                    escape_csv(&error)
+                );
+                csv
+            },
+        ),
+    }
+}
+
+/// A single solved part flattened out of its [`DayResult`], or the error of a day that could
+/// not be solved.
+struct Row<'a> {
+    year: Year,
+    day: Day,
+    input: String,
+    outcome: Result<&'a PartResult, String>,
+}
+
+fn rows(results: &[Result<DayResult, DayFailure>]) -> impl Iterator<Item = Row<'_>> {
+    results.iter().flat_map(|result| match result {
+        Ok(result) => result
+            .parts
+            .iter()
+            .map(|part| Row {
+                year: result.year,
+                day: result.day,
+                input: result.input.to_string(),
+                outcome: Ok(part),
+            })
+            .collect::<Vec<_>>(),
+        Err(failure) => vec![Row {
+            year: failure.year,
+            day: failure.day,
+            input: failure.input.to_string(),
+            outcome: Err(failure.error.to_string()),
+        }],
+    })
+}
+
+fn escape_json(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            ch if ch.is_control() => {
+                let _ = write!(escaped, "\\u{:04x}", ch as u32);
+            }
+            ch => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+fn escape_csv(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Error, InputKind, Part};
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    fn results() -> Vec<Result<DayResult, DayFailure>> {
+        let path = r#"in "dir"\3,a.txt"#;
+        vec![
+            Ok(DayResult {
+                year: 2025,
+                day: 12,
+                input: InputKind::Sample,
+                parse: Duration::from_micros(5),
+                parts: vec![
+                    PartResult {
+                        part: Part::One,
+                        answer: "2".to_string(),
+                        elapsed: Duration::from_nanos(1500),
+                    },
+                    PartResult {
+                        part: Part::Two,
+                        answer: "Say \"hi\", North Pole".to_string(),
+                        elapsed: Duration::from_nanos(20),
+                    },
+                ],
+                visualization: Some("AAB\nAB.".to_string()),
+            }),
+            Err(DayFailure {
+                year: 2025,
+                day: 3,
+                input: InputKind::Path(PathBuf::from(path)),
+                error: Error::MissingInput(path.to_string()),
+            }),
+        ]
+    }
+
+    #[test]
+    fn render_visual() {
+        let visual = render(Format::Visual, &results());
+        assert!(visual.contains("North Pole (20.00ns)\n\nAAB\nAB.\n"));
+        assert!(!render(Format::Text, &results()).contains("AAB"));
+        assert!(!visual.contains("Day 3"));
+    }
+
+    #[test]
+    fn render_json() {
+        let expected = r#"[
+  {"year": 2025, "day": 12, "part": 1, "input": "sample", "answer": "2", "elapsed_ns": 1500},
+  {"year": 2025, "day": 12, "part": 2, "input": "sample", "answer": "Say \"hi\", North Pole", "elapsed_ns": 20},
+  {"year": 2025, "day": 3, "input": "in \"dir\"\\3,a.txt", "error": "Missing input file: in \"dir\"\\3,a.txt"}
+]
+"#;
+        assert_eq!(expected, render(Format::Json, &results()));
+        assert_eq!("[]\n", render(Format::Json, &[]));
+    }
+
+    #[test]
+    fn render_csv() {
+        let expected = r#"year,day,part,input,answer,elapsed_ns,error
+2025,12,1,sample,2,1500,
+2025,12,2,sample,"Say ""hi"", North Pole",20,
+2025,3,,"in ""dir""\3,a.txt",,,"Missing input file: in ""dir""\3,a.txt"
+"#;
+        assert_eq!(expected, render(Format::Csv, &results()));
+    }
+}
@@ -0,0 +1,108 @@
+use crate::points::point_2d::Point2D;
+
+/// A compass direction on a grid, where rows increase going south.
+#[derive(Eq, Hash, PartialEq, Copy, Clone, Debug)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+#[allow(unused)]
+impl Direction {
+    /// Every direction, clockwise from [`Direction::North`].
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+
+    /// The four orthogonal directions, clockwise from [`Direction::North`].
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    fn index(self) -> usize {
+        Self::ALL.iter().position(|&dir| dir == self).unwrap()
+    }
+
+    /// The direction 90 degrees anticlockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self.index() + 6) % 8]
+    }
+
+    /// The direction 90 degrees clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self.index() + 2) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self.index() + 4) % 8]
+    }
+
+    /// The change in position from taking one step in this direction.
+    pub fn offset<T>(self) -> Point2D<T>
+    where
+        T: num_traits::Signed + num_traits::PrimInt,
+    {
+        let (row, col) = match self {
+            Direction::North => (-1, 0),
+            Direction::NorthEast => (-1, 1),
+            Direction::East => (0, 1),
+            Direction::SouthEast => (1, 1),
+            Direction::South => (1, 0),
+            Direction::SouthWest => (1, -1),
+            Direction::West => (0, -1),
+            Direction::NorthWest => (-1, -1),
+        };
+        Point2D::new(T::from(row).unwrap(), T::from(col).unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turn() {
+        assert_eq!(Direction::East, Direction::North.turn_right());
+        assert_eq!(Direction::West, Direction::North.turn_left());
+        assert_eq!(Direction::NorthWest, Direction::SouthWest.turn_right());
+        assert_eq!(Direction::SouthEast, Direction::NorthWest.opposite());
+        for dir in Direction::ALL {
+            assert_eq!(dir, dir.turn_left().turn_right());
+            assert_eq!(dir.offset::<i32>().turn_right(), dir.turn_right().offset());
+            assert_eq!(dir.offset::<i32>().turn_left(), dir.turn_left().offset());
+        }
+    }
+
+    #[test]
+    fn step() {
+        let point = Point2D::<i16>::new(2, 3);
+        assert_eq!(Point2D::new(1, 3), point.step(Direction::North));
+        assert_eq!(Point2D::new(3, 2), point.step(Direction::SouthWest));
+        assert!(!point.neighbors8().contains(&point));
+        assert_eq!(
+            [
+                Point2D::new(1, 3),
+                Point2D::new(2, 4),
+                Point2D::new(3, 3),
+                Point2D::new(2, 2),
+            ],
+            point.neighbors4()
+        );
+    }
+}
@@ -0,0 +1,251 @@
+use crate::points::point_2d::Point2D;
+use crate::Error;
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+#[allow(unused)]
+impl<T> Grid<T> {
+    /// Create a grid from its cells, given row by row.
+    ///
+    /// # Panics
+    /// If the number of cells is not `width * height`.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(width * height, cells.len(), "Grid must be {width}x{height}");
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn index_of<N>(&self, point: Point2D<N>) -> Option<usize>
+    where
+        N: num_traits::Signed + num_traits::PrimInt,
+    {
+        let row = point.row().to_usize()?;
+        let col = point.col().to_usize()?;
+        (row < self.height && col < self.width).then_some(row * self.width + col)
+    }
+
+    fn point_at<N>(&self, index: usize) -> Point2D<N>
+    where
+        N: num_traits::Signed + num_traits::PrimInt,
+    {
+        Point2D::new(
+            N::from(index / self.width).unwrap(),
+            N::from(index % self.width).unwrap(),
+        )
+    }
+
+    pub fn contains<N>(&self, point: Point2D<N>) -> bool
+    where
+        N: num_traits::Signed + num_traits::PrimInt,
+    {
+        self.index_of(point).is_some()
+    }
+
+    /// The cell at the point, or [`None`] if it is outside the grid.
+    pub fn get<N>(&self, point: Point2D<N>) -> Option<&T>
+    where
+        N: num_traits::Signed + num_traits::PrimInt,
+    {
+        self.index_of(point).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut<N>(&mut self, point: Point2D<N>) -> Option<&mut T>
+    where
+        N: num_traits::Signed + num_traits::PrimInt,
+    {
+        self.index_of(point).map(|index| &mut self.cells[index])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        self.rows().nth(row)
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        let cells = if col < self.width {
+            &self.cells[col..]
+        } else {
+            &[]
+        };
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// Every point in the grid, along with its cell, row by row.
+    pub fn iter<N>(&self) -> impl Iterator<Item = (Point2D<N>, &T)>
+    where
+        N: num_traits::Signed + num_traits::PrimInt,
+    {
+        (0..self.cells.len()).map(|index| (self.point_at(index), &self.cells[index]))
+    }
+
+    /// The cells around the point, including diagonals, that are inside the grid.
+    pub fn neighbors<N>(&self, point: Point2D<N>) -> impl Iterator<Item = (Point2D<N>, &T)>
+    where
+        N: num_traits::Signed + num_traits::PrimInt,
+    {
+        point
+            .neighbors8()
+            .into_iter()
+            .filter_map(|neighbor| self.get(neighbor).map(|cell| (neighbor, cell)))
+    }
+
+    /// The first point, row by row, holding the value, such as the `S` marking a start.
+    pub fn find<N>(&self, value: &T) -> Option<Point2D<N>>
+    where
+        T: PartialEq,
+        N: num_traits::Signed + num_traits::PrimInt,
+    {
+        let index = self.cells.iter().position(|cell| cell == value)?;
+        Some(self.point_at(index))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T, N> Index<Point2D<N>> for Grid<T>
+where
+    N: num_traits::Signed + num_traits::PrimInt + std::fmt::Debug,
+{
+    type Output = T;
+
+    fn index(&self, point: Point2D<N>) -> &Self::Output {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+
+impl<T, N> IndexMut<Point2D<N>> for Grid<T>
+where
+    N: num_traits::Signed + num_traits::PrimInt + std::fmt::Debug,
+{
+    fn index_mut(&mut self, point: Point2D<N>) -> &mut Self::Output {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside the grid"))
+    }
+}
+
+/// Parse one cell per character, with every line the same length.
+impl<T> std::str::FromStr for Grid<T>
+where
+    T: TryFrom<char>,
+{
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cells = Vec::with_capacity(s.len());
+        let mut width = None;
+        let mut height = 0;
+        for (row, line) in s.lines().enumerate() {
+            let before = cells.len();
+            for (col, ch) in line.chars().enumerate() {
+                let cell = T::try_from(ch).map_err(|_| Error::unexpected_char(row, col, ch))?;
+                cells.push(cell);
+            }
+
+            let len = cells.len() - before;
+            match width {
+                None => width = Some(len),
+                Some(width) if width != len => {
+                    return Err(Error::invalid_line(
+                        row,
+                        format!("Expected {width} cells, found {len}"),
+                    ));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Ok(Self::new(width.unwrap_or(0), height, cells))
+    }
+}
+
+impl<T> Display for Grid<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type Point = Point2D<i32>;
+
+    #[test]
+    fn parse_and_display() {
+        let text = "S.#\n.#.\n";
+        let grid = text.parse::<Grid<char>>().unwrap();
+        assert_eq!((3, 2), (grid.width(), grid.height()));
+        assert_eq!(text, grid.to_string());
+        assert_eq!('#', grid[Point::new(1, 1)]);
+        assert_eq!(None, grid.get(Point::new(2, 0)));
+        assert_eq!(None, grid.get(Point::new(0, -1)));
+        assert_eq!(Some(Point::new(0, 0)), grid.find(&'S'));
+        assert!("ab\nc".parse::<Grid<char>>().is_err());
+    }
+
+    #[test]
+    fn rows_columns_and_neighbors() {
+        let grid = "abc\ndef\n".parse::<Grid<char>>().unwrap();
+        assert_eq!(Some(&['d', 'e', 'f'][..]), grid.row(1));
+        assert_eq!(vec![&'b', &'e'], grid.column(1).collect::<Vec<_>>());
+        assert_eq!(0, grid.column(3).count());
+        assert_eq!(3, grid.columns().count());
+
+        let neighbors = grid
+            .neighbors(Point::new(0, 0))
+            .map(|(_, cell)| *cell)
+            .collect::<String>();
+        assert_eq!("bed", neighbors);
+        assert_eq!(6, grid.iter::<i32>().count());
+        assert_eq!("ABC\nDEF\n", grid.map(char::to_ascii_uppercase).to_string());
+    }
+}
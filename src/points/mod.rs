@@ -1,2 +1,5 @@
+pub mod direction;
+pub mod grid;
 pub mod point_2d;
 pub mod point_3d;
+pub mod point_n;
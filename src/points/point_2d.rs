@@ -1,175 +1,97 @@
-use std::ops::{Add, AddAssign};
+use crate::points::direction::Direction;
+use crate::points::point_n::PointN;
 
-#[derive(Eq, Hash, PartialEq, Copy, Clone, Debug, Ord, PartialOrd)]
-pub struct Point2D<T>
-where
-    T: num_traits::Signed + num_traits::PrimInt,
-{
-    pub row: T,
-    pub col: T,
-}
+/// A point on a grid, as its row and column.
+pub type Point2D<T> = PointN<T, 2>;
 
 impl<T> Point2D<T>
 where
     T: num_traits::Signed + num_traits::NumCast + num_traits::PrimInt + Copy,
 {
-    pub fn neighbors(&self) -> [Point2D<T>; 9] {
-        let mut neighbors = [Point2D::default(); 9];
-
-        let mut index = 0;
-        for row_inc in -1..=1 {
-            let row = self.row + T::from(row_inc).unwrap();
-            neighbors[index] = Point2D {
-                row,
-                col: self.col - T::one(),
-            };
-            neighbors[index + 1] = Point2D { row, col: self.col };
-            neighbors[index + 2] = Point2D {
-                row,
-                col: self.col + T::one(),
-            };
-            index += 3;
-        }
-
-        neighbors
+    pub const fn new(row: T, col: T) -> Self {
+        Self([row, col])
     }
 
-    #[allow(unused)]
-    pub fn distance(&self, point: Self) -> usize {
-        let x = T::to_isize(&(point.row - self.row)).unwrap();
-        let y = T::to_isize(&(point.col - self.col)).unwrap();
-
-        // isize * isize will always be positive
-        #[allow(clippy::cast_sign_loss)]
-        let dist = (x * x) as usize + (y * y) as usize;
-        dist.isqrt()
+    pub fn row(&self) -> T {
+        self.0[0]
     }
-}
-
-impl<T> Add for Point2D<T>
-where
-    T: num_traits::Signed + num_traits::NumCast + num_traits::PrimInt + Copy,
-{
-    type Output = Point2D<T>;
 
-    fn add(self, rhs: Self) -> Self::Output {
-        Self::Output {
-            row: self.row + rhs.row,
-            col: self.col + rhs.col,
-        }
+    pub fn col(&self) -> T {
+        self.0[1]
     }
-}
 
-impl<T> AddAssign for Point2D<T>
-where
-    T: num_traits::Signed + num_traits::NumCast + num_traits::PrimInt + Copy,
-{
-    fn add_assign(&mut self, rhs: Self) {
-        self.row = self.row + rhs.row;
-        self.col = self.col + rhs.col;
+    /// The point one step away in the direction.
+    pub fn step(&self, direction: Direction) -> Self {
+        *self + direction.offset()
     }
-}
-
-impl<T> Default for Point2D<T>
-where
-    T: num_traits::Signed + num_traits::NumCast + num_traits::PrimInt + Copy,
-{
-    fn default() -> Self {
-        Self {
-            row: T::zero(),
-            col: T::zero(),
-        }
-    }
-}
 
-#[allow(unused)]
-impl Point2D<i16> {
-    pub fn new(row: usize, col: usize) -> Self {
-        Self {
-            row: i16::try_from(row).unwrap(),
-            col: i16::try_from(col).unwrap(),
-        }
-    }
-}
-
-#[allow(unused)]
-impl Point2D<i32> {
-    pub fn new(row: usize, col: usize) -> Self {
-        Self {
-            row: i32::try_from(row).unwrap(),
-            col: i32::try_from(col).unwrap(),
-        }
+    /// The orthogonal neighbors, clockwise from north.
+    #[allow(unused)]
+    pub fn neighbors4(&self) -> [Point2D<T>; 4] {
+        Direction::CARDINAL.map(|direction| self.step(direction))
     }
-}
 
-#[allow(unused)]
-impl Point2D<i64> {
-    pub fn new(row: usize, col: usize) -> Self {
-        Self {
-            row: i64::try_from(row).unwrap(),
-            col: i64::try_from(col).unwrap(),
-        }
+    /// The orthogonal and diagonal neighbors, clockwise from north. The point itself is not
+    /// included.
+    pub fn neighbors8(&self) -> [Point2D<T>; 8] {
+        Direction::ALL.map(|direction| self.step(direction))
     }
-}
-
-fn try_from_internal<S, N>(value: S) -> Result<Point2D<N>, &'static str>
-where
-    S: AsRef<str>,
-    N: num_traits::Signed + num_traits::PrimInt + std::str::FromStr,
-{
-    let (lhs, rhs) = value
-        .as_ref()
-        .split_once(',')
-        .ok_or("Input did not contain ','")?;
-    let row = lhs.parse::<N>().map_err(|_| "Could not parse Lhs as i16")?;
-    let col = rhs.parse::<N>().map_err(|_| "Could not parse Rhs as i16")?;
-    Ok(Point2D { row, col })
-}
-
-impl TryFrom<&str> for Point2D<i16> {
-    type Error = &'static str;
 
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        try_from_internal(value)
+    /// Rotate 90 degrees anticlockwise around the origin.
+    #[allow(unused)]
+    pub fn turn_left(&self) -> Self {
+        Self::new(-self.col(), self.row())
     }
-}
-
-impl TryFrom<String> for Point2D<i16> {
-    type Error = &'static str;
 
-    fn try_from(value: String) -> Result<Self, Self::Error> {
-        try_from_internal(value)
+    /// Rotate 90 degrees clockwise around the origin.
+    #[allow(unused)]
+    pub fn turn_right(&self) -> Self {
+        Self::new(self.col(), -self.row())
     }
 }
 
-impl TryFrom<&str> for Point2D<i32> {
-    type Error = &'static str;
-
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        try_from_internal(value)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let mut a = Point2D::<i16>::new(3, -4);
+        let b = Point2D::<i16>::new(1, 2);
+        assert_eq!(Point2D::new(2, -6), a - b);
+        assert_eq!(Point2D::new(-3, 4), -a);
+        assert_eq!(Point2D::new(6, -8), a * 2);
+        assert_eq!(Point2D::new(1, -2), a / 2);
+        assert_eq!(Point2D::new(3, 4), a.abs());
+        assert_eq!(Point2D::new(1, -4), a.component_min(b));
+        assert_eq!(Point2D::new(3, 2), a.component_max(b));
+        assert_eq!(-5, a.dot(b));
+        a -= b;
+        assert_eq!(Point2D::new(2, -6), a);
     }
-}
 
-impl TryFrom<String> for Point2D<i32> {
-    type Error = &'static str;
-
-    fn try_from(value: String) -> Result<Self, Self::Error> {
-        try_from_internal(value)
+    #[test]
+    fn distances() {
+        let a = Point2D::<i16>::new(i16::MIN, 3);
+        let b = Point2D::<i16>::new(i16::MAX, -1);
+        assert_eq!(65535 * 65535 + 16, a.squared_distance(b));
+        assert_eq!(65535, a.distance(b));
+        assert_eq!(65539, a.manhattan(b));
+        assert_eq!(65535, b.chebyshev(a));
+        assert_eq!(0, a.manhattan(a));
     }
-}
-
-impl TryFrom<&str> for Point2D<i64> {
-    type Error = &'static str;
 
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        try_from_internal(value)
+    #[test]
+    fn convert_width() {
+        let small = Point2D::<i16>::new(-3, 4);
+        assert_eq!(Point2D::<i64>::new(-3, 4), small.into());
+        assert_eq!(Some(small), Point2D::<i64>::from(small).cast());
+        assert_eq!(None, Point2D::<i64>::new(1 << 40, 0).cast::<i32>());
     }
-}
-
-impl TryFrom<String> for Point2D<i64> {
-    type Error = &'static str;
 
-    fn try_from(value: String) -> Result<Self, Self::Error> {
-        try_from_internal(value)
+    #[test]
+    fn parse() {
+        assert_eq!(Ok(Point2D::<i32>::new(7, -1)), "7,-1".parse());
+        assert!(Point2D::<i32>::try_from("7").is_err());
     }
 }
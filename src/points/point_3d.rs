@@ -1,194 +1,90 @@
-use std::ops::{Add, AddAssign};
+use crate::points::point_n::PointN;
 
-#[derive(Eq, Hash, PartialEq, Copy, Clone, Debug, Ord, PartialOrd)]
-pub struct Point3D<T>
-where
-    T: num_traits::Signed + num_traits::PrimInt,
-{
-    pub x: T,
-    pub y: T,
-    pub z: T,
-}
+/// A point in space, as its x, y and z coordinates.
+pub type Point3D<T> = PointN<T, 3>;
 
+#[allow(unused)]
 impl<T> Point3D<T>
 where
     T: num_traits::Signed + num_traits::NumCast + num_traits::PrimInt + Copy,
 {
-    #[allow(unused)]
-    pub fn neighbors(&self) -> [Point3D<T>; 27] {
-        let mut neighbors = [Point3D::default(); 27];
-
-        let mut index = 0;
-        for x_inc in -1..=1 {
-            let x = self.x + T::from(x_inc).unwrap();
-            for y_inc in -1..=1 {
-                let y = self.y + T::from(y_inc).unwrap();
-                for z_inc in -1..=1 {
-                    neighbors[index] = Point3D {
-                        x,
-                        y,
-                        z: self.z - T::from(z_inc).unwrap(),
-                    };
-                    index += 1;
-                }
-            }
-        }
-
-        neighbors
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self([x, y, z])
     }
 
-    pub fn distance(&self, point: Point3D<T>) -> usize {
-        let x = T::to_isize(&(point.x - self.x)).unwrap();
-        let y = T::to_isize(&(point.y - self.y)).unwrap();
-        let z = T::to_isize(&(point.z - self.z)).unwrap();
-
-        // isize * isize will always be positive
-        #[allow(clippy::cast_sign_loss)]
-        let dist = (x * x) as usize + (y * y) as usize + (z * z) as usize;
-        dist.isqrt()
+    pub fn x(&self) -> T {
+        self.0[0]
     }
-}
-
-impl<T> Add for Point3D<T>
-where
-    T: num_traits::Signed + num_traits::NumCast + num_traits::PrimInt + Copy,
-{
-    type Output = Point3D<T>;
 
-    fn add(self, rhs: Self) -> Self::Output {
-        Self::Output {
-            x: self.x + rhs.x,
-            y: self.y + rhs.y,
-            z: self.z + rhs.z,
-        }
+    pub fn y(&self) -> T {
+        self.0[1]
     }
-}
 
-impl<T> AddAssign for Point3D<T>
-where
-    T: num_traits::Signed + num_traits::NumCast + num_traits::PrimInt + Copy,
-{
-    fn add_assign(&mut self, rhs: Self) {
-        self.x = self.x + rhs.x;
-        self.y = self.y + rhs.y;
-        self.z = self.z + rhs.z;
+    pub fn z(&self) -> T {
+        self.0[2]
     }
-}
 
-impl<T> Default for Point3D<T>
-where
-    T: num_traits::Signed + num_traits::NumCast + num_traits::PrimInt + Copy,
-{
-    fn default() -> Self {
-        Self {
-            x: T::zero(),
-            y: T::zero(),
-            z: T::zero(),
-        }
+    /// The point perpendicular to both points, following the right-hand rule.
+    pub fn cross(&self, other: Self) -> Self {
+        Self::new(
+            self.y() * other.z() - self.z() * other.y(),
+            self.z() * other.x() - self.x() * other.z(),
+            self.x() * other.y() - self.y() * other.x(),
+        )
     }
 }
 
-#[allow(unused)]
-impl Point3D<i16> {
-    pub fn new(x: usize, y: usize, z: usize) -> Self {
-        Self {
-            x: i16::try_from(x).unwrap(),
-            y: i16::try_from(y).unwrap(),
-            z: i16::try_from(z).unwrap(),
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let mut a = Point3D::<i32>::new(1, -2, 3);
+        let b = Point3D::<i32>::new(4, 5, -6);
+        assert_eq!(Point3D::new(-3, -7, 9), a - b);
+        assert_eq!(Point3D::new(-1, 2, -3), -a);
+        assert_eq!(Point3D::new(3, -6, 9), a * 3);
+        assert_eq!(Point3D::new(2, 2, -3), b / 2);
+        assert_eq!(Point3D::new(1, 2, 3), a.abs());
+        assert_eq!(Point3D::new(1, -2, -6), a.component_min(b));
+        assert_eq!(Point3D::new(4, 5, 3), a.component_max(b));
+        assert_eq!(-24, a.dot(b));
+        let cross = a.cross(b);
+        assert_eq!(Point3D::new(-3, 18, 13), cross);
+        assert_eq!((0, 0), (cross.dot(a), cross.dot(b)));
+        a -= b;
+        assert_eq!(Point3D::new(-3, -7, 9), a);
     }
-}
 
-#[allow(unused)]
-impl Point3D<i32> {
-    pub fn new(x: usize, y: usize, z: usize) -> Self {
-        Self {
-            x: i32::try_from(x).unwrap(),
-            y: i32::try_from(y).unwrap(),
-            z: i32::try_from(z).unwrap(),
-        }
+    #[test]
+    fn distances() {
+        let a = Point3D::<i64>::default();
+        let near = Point3D::<i64>::new(3, 4, 12);
+        let far = Point3D::<i64>::new(3, 4, -12) * (1 << 40);
+        assert_eq!(169, a.squared_distance(near));
+        assert_eq!(13, near.distance(a));
+        assert_eq!(19, a.manhattan(near));
+        assert_eq!(12, a.chebyshev(near));
+        assert_eq!(169 << 80, a.squared_distance(far));
+        // Both distances round down to 13, but only one is exactly 13
+        let off = Point3D::<i64>::new(3, 4, 13);
+        assert_eq!(a.distance(near), a.distance(off));
+        assert!(a.squared_distance(near) < a.squared_distance(off));
     }
-}
 
-#[allow(unused)]
-impl Point3D<i64> {
-    pub fn new(x: usize, y: usize, z: usize) -> Self {
-        Self {
-            x: i64::try_from(x).unwrap(),
-            y: i64::try_from(y).unwrap(),
-            z: i64::try_from(z).unwrap(),
-        }
+    #[test]
+    fn convert_width() {
+        let small = Point3D::<i16>::new(-3, 4, 5);
+        assert_eq!(Point3D::<i64>::new(-3, 4, 5), small.into());
+        assert_eq!(Some(small), Point3D::<i32>::from(small).cast());
+        assert_eq!(None, Point3D::<i32>::new(1 << 20, 0, 0).cast::<i16>());
     }
-}
-
-fn try_from_internal<S, N>(value: S) -> Result<Point3D<N>, &'static str>
-where
-    S: AsRef<str>,
-    N: num_traits::Signed + num_traits::PrimInt + std::str::FromStr,
-{
-    let mut parts = value.as_ref().split(',');
-    let x = parts
-        .next()
-        .ok_or("Missing x")?
-        .parse::<N>()
-        .map_err(|_| "Could not parse x as number")?;
-    let y = parts
-        .next()
-        .ok_or("Missing y")?
-        .parse::<N>()
-        .map_err(|_| "Could not parse y as number")?;
-    let z = parts
-        .next()
-        .ok_or("Missing z")?
-        .parse::<N>()
-        .map_err(|_| "Could not parse z as number")?;
-    Ok(Point3D { x, y, z })
-}
-
-impl TryFrom<&str> for Point3D<i16> {
-    type Error = &'static str;
-
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        try_from_internal(value)
-    }
-}
-
-impl TryFrom<String> for Point3D<i16> {
-    type Error = &'static str;
-
-    fn try_from(value: String) -> Result<Self, Self::Error> {
-        try_from_internal(value)
-    }
-}
-
-impl TryFrom<&str> for Point3D<i32> {
-    type Error = &'static str;
-
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        try_from_internal(value)
-    }
-}
-
-impl TryFrom<String> for Point3D<i32> {
-    type Error = &'static str;
-
-    fn try_from(value: String) -> Result<Self, Self::Error> {
-        try_from_internal(value)
-    }
-}
-
-impl TryFrom<&str> for Point3D<i64> {
-    type Error = &'static str;
-
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        try_from_internal(value)
-    }
-}
-
-impl TryFrom<String> for Point3D<i64> {
-    type Error = &'static str;
 
-    fn try_from(value: String) -> Result<Self, Self::Error> {
-        try_from_internal(value)
+    #[test]
+    fn neighbors() {
+        let neighbors = Point3D::<i16>::new(1, 1, 1).neighbors();
+        assert_eq!(26, neighbors.len());
+        assert!(neighbors.contains(&Point3D::new(0, 2, 1)));
     }
 }
@@ -0,0 +1,289 @@
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+/// A point with `D` integer coordinates.
+#[derive(Eq, Hash, PartialEq, Copy, Clone, Debug, Ord, PartialOrd)]
+pub struct PointN<T, const D: usize>(pub [T; D])
+where
+    T: num_traits::Signed + num_traits::PrimInt;
+
+impl<T, const D: usize> PointN<T, D>
+where
+    T: num_traits::Signed + num_traits::NumCast + num_traits::PrimInt + Copy,
+{
+    fn map(self, f: impl FnMut(T) -> T) -> Self {
+        Self(self.0.map(f))
+    }
+
+    fn zip_with(self, other: Self, f: impl Fn(T, T) -> T) -> Self {
+        Self(std::array::from_fn(|i| f(self.0[i], other.0[i])))
+    }
+
+    /// Every point that differs by at most one in each coordinate, including diagonals.
+    /// There are `3^D - 1` of them, as the point itself is not included.
+    #[allow(unused)]
+    pub fn neighbors(&self) -> Vec<Self> {
+        let count = 3usize.pow(u32::try_from(D).unwrap());
+        (0..count)
+            .map(|mut digits| {
+                self.map(|coord| {
+                    let offset = T::from(digits % 3).unwrap() - T::one();
+                    digits /= 3;
+                    coord + offset
+                })
+            })
+            .filter(|neighbor| neighbor != self)
+            .collect()
+    }
+
+    /// The smallest of each coordinate of the two points.
+    #[allow(unused)]
+    pub fn component_min(&self, other: Self) -> Self {
+        self.zip_with(other, T::min)
+    }
+
+    /// The largest of each coordinate of the two points.
+    #[allow(unused)]
+    pub fn component_max(&self, other: Self) -> Self {
+        self.zip_with(other, T::max)
+    }
+
+    #[allow(unused)]
+    pub fn abs(&self) -> Self {
+        self.map(|coord| coord.abs())
+    }
+
+    #[allow(unused)]
+    pub fn dot(&self, other: Self) -> T {
+        (0..D).fold(T::zero(), |sum, i| sum + self.0[i] * other.0[i])
+    }
+
+    /// Convert to another integer width, or [`None`] if a coordinate does not fit.
+    #[allow(unused)]
+    pub fn cast<U>(&self) -> Option<PointN<U, D>>
+    where
+        U: num_traits::Signed + num_traits::PrimInt,
+    {
+        let mut coords = [U::zero(); D];
+        for (coord, &from) in coords.iter_mut().zip(&self.0) {
+            *coord = U::from(from)?;
+        }
+        Some(PointN(coords))
+    }
+
+    /// The distance along a straight line, rounded down. Use [`PointN::squared_distance`]
+    /// to compare distances exactly.
+    #[allow(unused)]
+    pub fn distance(&self, point: Self) -> usize {
+        usize::try_from(self.squared_distance(point).isqrt()).unwrap()
+    }
+
+    /// The square of the distance along a straight line.
+    pub fn squared_distance(&self, point: Self) -> u128 {
+        self.differences(point).iter().map(|diff| diff * diff).sum()
+    }
+
+    /// The distance when only moving along one axis at a time.
+    #[allow(unused)]
+    pub fn manhattan(&self, point: Self) -> u128 {
+        self.differences(point).iter().sum()
+    }
+
+    /// The distance when diagonal moves are allowed.
+    #[allow(unused)]
+    pub fn chebyshev(&self, point: Self) -> u128 {
+        self.differences(point).into_iter().max().unwrap_or(0)
+    }
+
+    fn differences(&self, point: Self) -> [u128; D] {
+        std::array::from_fn(|i| {
+            (point.0[i].to_i128().unwrap() - self.0[i].to_i128().unwrap()).unsigned_abs()
+        })
+    }
+}
+
+impl<T, const D: usize> Add for PointN<T, D>
+where
+    T: num_traits::Signed + num_traits::NumCast + num_traits::PrimInt + Copy,
+{
+    type Output = PointN<T, D>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a + b)
+    }
+}
+
+impl<T, const D: usize> AddAssign for PointN<T, D>
+where
+    T: num_traits::Signed + num_traits::NumCast + num_traits::PrimInt + Copy,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T, const D: usize> Sub for PointN<T, D>
+where
+    T: num_traits::Signed + num_traits::NumCast + num_traits::PrimInt + Copy,
+{
+    type Output = PointN<T, D>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.zip_with(rhs, |a, b| a - b)
+    }
+}
+
+impl<T, const D: usize> SubAssign for PointN<T, D>
+where
+    T: num_traits::Signed + num_traits::NumCast + num_traits::PrimInt + Copy,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T, const D: usize> Neg for PointN<T, D>
+where
+    T: num_traits::Signed + num_traits::NumCast + num_traits::PrimInt + Copy,
+{
+    type Output = PointN<T, D>;
+
+    fn neg(self) -> Self::Output {
+        self.map(|coord| -coord)
+    }
+}
+
+impl<T, const D: usize> Mul<T> for PointN<T, D>
+where
+    T: num_traits::Signed + num_traits::NumCast + num_traits::PrimInt + Copy,
+{
+    type Output = PointN<T, D>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.map(|coord| coord * rhs)
+    }
+}
+
+/// Divide each coordinate, rounding towards zero.
+impl<T, const D: usize> Div<T> for PointN<T, D>
+where
+    T: num_traits::Signed + num_traits::NumCast + num_traits::PrimInt + Copy,
+{
+    type Output = PointN<T, D>;
+
+    fn div(self, rhs: T) -> Self::Output {
+        self.map(|coord| coord / rhs)
+    }
+}
+
+impl<T, const D: usize> Default for PointN<T, D>
+where
+    T: num_traits::Signed + num_traits::NumCast + num_traits::PrimInt + Copy,
+{
+    fn default() -> Self {
+        Self([T::zero(); D])
+    }
+}
+
+impl<const D: usize> From<PointN<i16, D>> for PointN<i32, D> {
+    fn from(value: PointN<i16, D>) -> Self {
+        Self(value.0.map(i32::from))
+    }
+}
+
+impl<const D: usize> From<PointN<i16, D>> for PointN<i64, D> {
+    fn from(value: PointN<i16, D>) -> Self {
+        Self(value.0.map(i64::from))
+    }
+}
+
+impl<const D: usize> From<PointN<i32, D>> for PointN<i64, D> {
+    fn from(value: PointN<i32, D>) -> Self {
+        Self(value.0.map(i64::from))
+    }
+}
+
+/// Parse `D` comma separated coordinates, such as `1,-2,3`.
+impl<T, const D: usize> std::str::FromStr for PointN<T, D>
+where
+    T: num_traits::Signed + num_traits::PrimInt + std::str::FromStr,
+{
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = s.split(',').collect::<Vec<_>>();
+        if parts.len() != D {
+            return Err(format!("Expected {D} coordinates, found {}", parts.len()));
+        }
+
+        let mut coords = [T::zero(); D];
+        for (coord, part) in coords.iter_mut().zip(parts) {
+            *coord = part
+                .parse()
+                .map_err(|_| format!("Could not parse {part:?} as a number"))?;
+        }
+        Ok(Self(coords))
+    }
+}
+
+impl<T, const D: usize> TryFrom<&str> for PointN<T, D>
+where
+    T: num_traits::Signed + num_traits::PrimInt + std::str::FromStr,
+{
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl<T, const D: usize> TryFrom<String> for PointN<T, D>
+where
+    T: num_traits::Signed + num_traits::PrimInt + std::str::FromStr,
+{
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn neighbors() {
+        let point = PointN::<i32, 4>([0, 5, -5, 1]);
+        let neighbors = point.neighbors();
+        assert_eq!(80, neighbors.len());
+        assert!(!neighbors.contains(&point));
+        assert!(neighbors.iter().all(|n| point.chebyshev(*n) == 1));
+        assert_eq!(2, PointN::<i16, 1>([0]).neighbors().len());
+    }
+
+    #[test]
+    fn parse_any_arity() {
+        assert_eq!(Ok(PointN::<i64, 4>([1, -2, 3, 40])), "1,-2,3,40".parse());
+        assert_eq!(Ok(PointN::<i16, 1>([7])), PointN::try_from("7"));
+        assert_eq!(
+            Err("Expected 3 coordinates, found 2".to_string()),
+            "1,2".parse::<PointN<i32, 3>>()
+        );
+        assert_eq!(
+            Err("Could not parse \"b\" as a number".to_string()),
+            PointN::<i32, 2>::try_from("1,b".to_string())
+        );
+    }
+
+    #[test]
+    fn arithmetic() {
+        let mut a = PointN::<i64, 4>([1, -2, 3, -4]);
+        let b = PointN::<i64, 4>([1, 1, 1, 1]);
+        assert_eq!(PointN([2, -1, 4, -3]), a + b);
+        assert_eq!(-2, a.dot(b));
+        assert_eq!(10, a.manhattan(PointN::default()));
+        a += b;
+        a -= b * 2;
+        assert_eq!(PointN([0, -3, 2, -5]), a);
+    }
+}
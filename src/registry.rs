@@ -0,0 +1,76 @@
+use crate::{Day, DynSolution, Error, Input, Year};
+
+/// A single solved day, and how to build its [`DynSolution`].
+pub struct Entry {
+    pub year: Year,
+    pub day: Day,
+    pub solution: fn(Input) -> Result<Box<dyn DynSolution>, Error>,
+}
+
+impl Entry {
+    pub const fn new(
+        year: Year,
+        day: Day,
+        solution: fn(Input) -> Result<Box<dyn DynSolution>, Error>,
+    ) -> Self {
+        Self {
+            year,
+            day,
+            solution,
+        }
+    }
+}
+
+/// The solved days of every year, ordered by [`Year`]. `advent-of-code new-day` adds new
+/// years here.
+pub const YEARS: &[&[Entry]] = &[crate::year_2025::DAYS];
+
+/// Every solved day, ordered by year and then day.
+pub fn entries() -> impl Iterator<Item = &'static Entry> {
+    YEARS.iter().flat_map(|days| days.iter())
+}
+
+/// The solved days of the year, ordered by [`Day`].
+pub fn days(year: Year) -> impl Iterator<Item = &'static Entry> {
+    entries().filter(move |entry| entry.year == year)
+}
+
+/// The most recent year with a solved day.
+pub fn latest_year() -> Option<Year> {
+    entries().map(|entry| entry.year).max()
+}
+
+/// Find the [`Entry`] for the given day, if it has been solved.
+pub fn find(year: Year, day: Day) -> Option<&'static Entry> {
+    entries().find(|entry| entry.year == year && entry.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_are_ordered() {
+        let entries = entries().collect::<Vec<_>>();
+        assert!(entries
+            .windows(2)
+            .all(|pair| (pair[0].year, pair[0].day) < (pair[1].year, pair[1].day)));
+        assert_eq!(entries.last().map(|entry| entry.year), latest_year());
+        assert!(find(2025, 1).is_some());
+        assert!(find(2024, 1).is_none());
+    }
+
+    #[test]
+    fn samples_load() {
+        for entry in entries() {
+            let solution = (entry.solution)(Input::Sample(entry.year, entry.day));
+            assert!(
+                solution.is_ok(),
+                "{} Day {}: {:?}",
+                entry.year,
+                entry.day,
+                solution.err()
+            );
+        }
+    }
+}
@@ -0,0 +1,309 @@
+use crate::registry::Entry;
+use crate::{Day, DynSolution, Error, Input, InputKind, Part, Year};
+use std::any::Any;
+use std::fmt::Display;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+type Solver = fn(&dyn DynSolution) -> Box<dyn Display>;
+
+/// The answer to one part of a day, and how long it took to solve.
+pub struct PartResult {
+    pub part: Part,
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+/// The answers to a single day, along with the time spent parsing the input.
+pub struct DayResult {
+    pub year: Year,
+    pub day: Day,
+    pub input: InputKind,
+    pub parse: Duration,
+    pub parts: Vec<PartResult>,
+    /// The drawing of the solution, when one was asked for and the day can draw one.
+    pub visualization: Option<String>,
+}
+
+/// A day that could not be solved, such as when its input is missing.
+#[derive(Debug)]
+pub struct DayFailure {
+    pub year: Year,
+    pub day: Day,
+    pub input: InputKind,
+    pub error: Error,
+}
+
+impl Display for DayFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Day {}: {}", self.day, self.error)
+    }
+}
+
+impl DayResult {
+    fn elapsed(&self, part: Part) -> Option<Duration> {
+        self.parts
+            .iter()
+            .find(|result| result.part == part)
+            .map(|result| result.elapsed)
+    }
+
+    pub fn total(&self) -> Duration {
+        self.parse
+            + self
+                .parts
+                .iter()
+                .map(|result| result.elapsed)
+                .sum::<Duration>()
+    }
+}
+
+impl Display for DayResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Day {} (parsed in {:.2?})", self.day, self.parse)?;
+        for result in &self.parts {
+            writeln!(
+                f,
+                "Part {}: {} ({:.2?})",
+                result.part, result.answer, result.elapsed
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Build the day from the input and solve the selected parts, timing each step.
+pub fn run(entry: &Entry, input: Input, part: Part) -> Result<DayResult, Error> {
+    solve(entry, input, part, false, false)
+}
+
+/// Run every job on a pool of `threads` worker threads, solving the parts of each day on
+/// their own thread. Each step is timed on the thread that runs it. The results are in the
+/// same order as the jobs. With `visualize`, each day is also asked to draw its solution.
+pub fn run_all(
+    jobs: Vec<(&Entry, Input)>,
+    part: Part,
+    threads: usize,
+    visualize: bool,
+) -> Vec<Result<DayResult, DayFailure>> {
+    let attempt = |entry: &Entry, input: Input, parallel| {
+        let kind = input.kind();
+        solve(entry, input, part, parallel, visualize).map_err(|error| DayFailure {
+            year: entry.year,
+            day: entry.day,
+            input: kind,
+            error,
+        })
+    };
+    if threads <= 1 {
+        return jobs
+            .into_iter()
+            .map(|(entry, input)| attempt(entry, input, false))
+            .collect();
+    }
+
+    let queue = Mutex::new(jobs.into_iter().enumerate());
+    let results = Mutex::new(Vec::new());
+    thread::scope(|scope| {
+        for _ in 0..threads {
+            scope.spawn(|| loop {
+                let Some((index, (entry, input))) = queue.lock().unwrap().next() else {
+                    break;
+                };
+                let result = attempt(entry, input, true);
+                results.lock().unwrap().push((index, result));
+            });
+        }
+    });
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+/// Solve the day, turning a panic while parsing or solving into an [`Error::Panicked`] so
+/// that one broken day does not stop the days after it.
+fn solve(
+    entry: &Entry,
+    input: Input,
+    part: Part,
+    parallel: bool,
+    visualize: bool,
+) -> Result<DayResult, Error> {
+    panic::catch_unwind(AssertUnwindSafe(|| {
+        solve_parts(entry, input, part, parallel, visualize)
+    }))
+    .unwrap_or_else(|payload| Err(Error::Panicked(panic_message(payload))))
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "unknown panic".to_string(),
+        },
+    }
+}
+
+fn solve_parts(
+    entry: &Entry,
+    input: Input,
+    part: Part,
+    parallel: bool,
+    visualize: bool,
+) -> Result<DayResult, Error> {
+    let kind = input.kind();
+    let start = Instant::now();
+    let solution = (entry.solution)(input)?;
+    let parse = start.elapsed();
+
+    let solvers: [(Part, Solver); 2] = [(Part::One, |s| s.part1()), (Part::Two, |s| s.part2())];
+    let selected = solvers
+        .into_iter()
+        .filter(|(selected, _)| part.includes(*selected));
+    let solution = &*solution;
+    let parts = if parallel {
+        thread::scope(|scope| {
+            let handles = selected
+                .map(|(selected, solver)| scope.spawn(move || time(selected, solver, solution)))
+                .collect::<Vec<_>>();
+            handles
+                .into_iter()
+                .map(|handle| {
+                    handle
+                        .join()
+                        .unwrap_or_else(|err| std::panic::resume_unwind(err))
+                })
+                .collect()
+        })
+    } else {
+        selected
+            .map(|(selected, solver)| time(selected, solver, solution))
+            .collect()
+    };
+
+    Ok(DayResult {
+        year: entry.year,
+        day: entry.day,
+        input: kind,
+        parse,
+        parts,
+        visualization: visualize.then(|| solution.visualize()).flatten(),
+    })
+}
+
+fn time(part: Part, solver: Solver, solution: &dyn DynSolution) -> PartResult {
+    let start = Instant::now();
+    let answer = solver(solution).to_string();
+    PartResult {
+        part,
+        answer,
+        elapsed: start.elapsed(),
+    }
+}
+
+/// Render a table of the time spent on each day, with a total across all of them.
+pub fn summary(results: &[&DayResult]) -> String {
+    let cell = |duration: Option<Duration>| match duration {
+        Some(duration) => format!("{duration:>10.2?}"),
+        None => format!("{:>10}", "-"),
+    };
+
+    let mut table = format!(
+        "{:>4} | {:>10} | {:>10} | {:>10} | {:>10}\n",
+        "Day", "Parse", "Part 1", "Part 2", "Total"
+    );
+    for result in results {
+        table += &format!(
+            "{:>4} | {} | {} | {} | {}\n",
+            result.day,
+            cell(Some(result.parse)),
+            cell(result.elapsed(Part::One)),
+            cell(result.elapsed(Part::Two)),
+            cell(Some(result.total())),
+        );
+    }
+
+    let sum = |f: fn(&DayResult) -> Option<Duration>| {
+        results.iter().copied().filter_map(f).sum::<Duration>()
+    };
+    table += &format!(
+        "{:>4} | {} | {} | {} | {}\n",
+        "All",
+        cell(Some(sum(|r| Some(r.parse)))),
+        cell(Some(sum(|r| r.elapsed(Part::One)))),
+        cell(Some(sum(|r| r.elapsed(Part::Two)))),
+        cell(Some(sum(|r| Some(r.total())))),
+    );
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn run_selected_parts() {
+        let entry = registry::find(2025, 1).unwrap();
+        let result = run(entry, Input::Sample(2025, 1), Part::Two).unwrap();
+        assert_eq!(1, result.parts.len());
+        assert_eq!("6", result.parts[0].answer);
+
+        let table = summary(&[&result]);
+        assert_eq!(3, table.lines().count());
+        assert!(table.lines().nth(1).unwrap().contains(" - "));
+    }
+
+    #[test]
+    fn run_all_in_order() {
+        let jobs = || {
+            registry::days(2025)
+                .take(6)
+                .map(|entry| (entry, Input::Sample(entry.year, entry.day)))
+                .collect::<Vec<_>>()
+        };
+        let answers = |threads| {
+            run_all(jobs(), Part::Both, threads, false)
+                .into_iter()
+                .map(|result| {
+                    let result = result.unwrap();
+                    let answers = result.parts.iter().map(|part| part.answer.clone());
+                    (result.day, answers.collect::<Vec<_>>())
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let sequential = answers(1);
+        assert_eq!(
+            vec![1, 2, 3, 4, 5, 6],
+            sequential.iter().map(|(day, _)| *day).collect::<Vec<_>>()
+        );
+        assert_eq!(sequential, answers(4));
+    }
+
+    #[test]
+    fn run_all_samples() {
+        let jobs = registry::days(2025)
+            .map(|entry| (entry, Input::Sample(entry.year, entry.day)))
+            .collect::<Vec<_>>();
+        let count = jobs.len();
+        let results = run_all(jobs, Part::Both, 4, false);
+        assert_eq!(count, results.len());
+
+        // The Day 11 sample has no `svr` device, so part 2 panics. The days after it still run.
+        for result in &results {
+            match result {
+                Ok(result) => assert_ne!(11, result.day),
+                Err(failure) => {
+                    assert_eq!(11, failure.day);
+                    assert!(matches!(failure.error, Error::Panicked(_)));
+                }
+            }
+        }
+        assert!(results.iter().any(|result| result.is_err()));
+    }
+}
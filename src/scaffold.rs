@@ -0,0 +1,373 @@
+use crate::{Day, Year};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = "use crate::{erase, read_to_string, DynSolution, Error, Input, Solution};
+use std::str::FromStr;
+
+pub const DAY: u8 = ${DAY};
+
+pub struct Day${DAY};
+
+impl Solution<usize, usize> for Day${DAY} {
+    fn part1(&self) -> usize {
+        0
+    }
+
+    fn part2(&self) -> usize {
+        0
+    }
+}
+
+impl TryFrom<Input> for Day${DAY} {
+    type Error = Error;
+
+    fn try_from(value: Input) -> Result<Self, Self::Error> {
+        read_to_string(&value)?.parse()
+    }
+}
+
+impl FromStr for Day${DAY} {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let _lines = s.lines();
+        Ok(Day${DAY} {})
+    }
+}
+
+pub fn solution(input: Input) -> Result<Box<dyn DynSolution>, Error> {
+    Ok(erase(Day${DAY}::try_from(input)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::year_${YEAR}::YEAR;
+
+    #[test]
+    fn part_1() {
+        let day_sample = Day${DAY}::try_from(Input::Sample(YEAR, DAY)).unwrap();
+        assert_eq!(0, day_sample.part1());
+    }
+
+    #[test]
+    fn part_2() {
+        let day_sample = Day${DAY}::try_from(Input::Sample(YEAR, DAY)).unwrap();
+        assert_eq!(0, day_sample.part2());
+    }
+}
+";
+
+const YEAR_TEMPLATE: &str = "use crate::registry::Entry;
+use crate::Year;
+
+pub mod day_${DAY};
+
+pub const YEAR: Year = ${YEAR};
+
+/// All solved days of the year, ordered by [`Day`](crate::Day).
+pub const DAYS: &[Entry] = &[
+    Entry::new(YEAR, day_${DAY}::DAY, day_${DAY}::solution),
+];
+";
+
+const INPUT_FILES: [&str; 3] = ["input.sample", "input.part1", "input.part2"];
+
+/// The source of a freshly scaffolded day.
+pub fn template(year: Year, day: Day) -> String {
+    fill(TEMPLATE, year, day)
+}
+
+fn fill(template: &str, year: Year, day: Day) -> String {
+    template
+        .replace("${YEAR}", &year.to_string())
+        .replace("${DAY}", &day.to_string())
+}
+
+/// Create `src/year_{year}/day_{day}.rs` and the empty `inputs/{year}/{day}/input.*` files
+/// under `root`, and register the day with its year module. The first day of a new year
+/// also creates the year module, and adds it to `src/lib.rs` and `src/registry.rs`.
+/// Refuses to touch a day that already exists. Returns every file that was created or
+/// changed.
+pub fn new_day(root: &Path, year: Year, day: Day) -> Result<Vec<PathBuf>, String> {
+    if day == 0 || day > 25 {
+        return Err(format!("Invalid day: {day}"));
+    }
+
+    let src = root.join("src");
+    let year_dir = src.join(format!("year_{year}"));
+    let source = year_dir.join(format!("day_{day}.rs"));
+    let module = year_dir.join("mod.rs");
+    if source.exists() {
+        return Err(format!("{} already exists", source.display()));
+    }
+
+    let mut writes = Vec::new();
+    if module.exists() {
+        let contents = register_module(&read(&module)?, "day", day.into())?;
+        writes.push((module, register_entry(&contents, day)?));
+    } else {
+        let lib = src.join("lib.rs");
+        let registry = src.join("registry.rs");
+        let lib_contents = register_module(&read(&lib)?, "year", year)?;
+        let registry_contents = register_year(&read(&registry)?, year)?;
+        writes.push((module, fill(YEAR_TEMPLATE, year, day)));
+        writes.push((lib, lib_contents));
+        writes.push((registry, registry_contents));
+    }
+
+    let inputs = root
+        .join("inputs")
+        .join(year.to_string())
+        .join(day.to_string());
+    let mut changed = Vec::new();
+    for dir in [&inputs, &year_dir] {
+        fs::create_dir_all(dir).map_err(|err| format!("{}: {err}", dir.display()))?;
+    }
+    for file in INPUT_FILES {
+        let path = inputs.join(file);
+        // Keep any input that was already downloaded.
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|err| format!("{}: {err}", path.display()))?;
+        changed.push(path);
+    }
+
+    OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&source)
+        .and_then(|mut file| file.write_all(template(year, day).as_bytes()))
+        .map_err(|err| format!("{}: {err}", source.display()))?;
+    changed.push(source);
+
+    for (path, contents) in writes {
+        fs::write(&path, contents).map_err(|err| format!("{}: {err}", path.display()))?;
+        changed.push(path);
+    }
+    Ok(changed)
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+/// Add `pub mod {kind}_{number};` to the list of modules of that kind, keeping it sorted.
+fn register_module(source: &str, kind: &str, number: u16) -> Result<String, String> {
+    let prefix = format!("pub mod {kind}_");
+    let module = format!("{prefix}{number};");
+    let mut lines = source.lines().collect::<Vec<_>>();
+    if lines.contains(&module.as_str()) {
+        return Err(format!("Module {kind}_{number} is already declared"));
+    }
+
+    let position = lines
+        .iter()
+        .position(|line| line.starts_with(&prefix) && **line > *module)
+        .or_else(|| {
+            lines
+                .iter()
+                .rposition(|line| line.starts_with(&prefix))
+                .map(|index| index + 1)
+        })
+        .ok_or(format!("Could not find any {kind} modules"))?;
+    lines.insert(position, &module);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Find the lines of the `pub const {name}` array, from the declaration up to the `];`.
+fn array_lines(lines: &[&str], name: &str) -> Result<(usize, usize), String> {
+    let start = lines
+        .iter()
+        .position(|line| line.starts_with(&format!("pub const {name}:")))
+        .ok_or(format!("Could not find {name}"))?;
+    let end = lines[start..]
+        .iter()
+        .position(|line| line.ends_with("];"))
+        .map(|index| start + index)
+        .ok_or(format!("Could not find the end of {name}"))?;
+    Ok((start, end))
+}
+
+/// Add an [`Entry`](crate::registry::Entry) for the day to the year's `DAYS`, keeping it
+/// ordered.
+fn register_entry(module: &str, day: Day) -> Result<String, String> {
+    let entry_day = |line: &str| {
+        line.trim()
+            .strip_prefix("Entry::new(YEAR, day_")?
+            .split_once("::")?
+            .0
+            .parse::<Day>()
+            .ok()
+    };
+
+    let mut lines = module.lines().collect::<Vec<_>>();
+    if lines.iter().any(|line| entry_day(line) == Some(day)) {
+        return Err(format!("Day {day} is already in DAYS"));
+    }
+
+    let (start, end) = array_lines(&lines, "DAYS")?;
+    let position = (start + 1..end)
+        .find(|&index| entry_day(lines[index]).is_some_and(|other| other > day))
+        .unwrap_or(end);
+
+    let entry = format!("    Entry::new(YEAR, day_{day}::DAY, day_{day}::solution),");
+    lines.insert(position, &entry);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Add the year's `DAYS` to the registry's `YEARS`, keeping it ordered.
+fn register_year(registry: &str, year: Year) -> Result<String, String> {
+    let mut lines = registry.lines().collect::<Vec<_>>();
+    let (start, end) = array_lines(&lines, "YEARS")?;
+    let declaration = lines[start..=end].join("\n");
+    let (head, items) = declaration
+        .rsplit_once("&[")
+        .ok_or("Could not find the items of YEARS")?;
+
+    let item_year = |item: &str| {
+        item.strip_prefix("crate::year_")?
+            .strip_suffix("::DAYS")?
+            .parse::<Year>()
+            .ok()
+    };
+    let mut years = items
+        .trim_end_matches("];")
+        .split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(|item| item_year(item).ok_or(format!("Unexpected item in YEARS: {item}")))
+        .collect::<Result<Vec<_>, _>>()?;
+    if years.contains(&year) {
+        return Err(format!("Year {year} is already in YEARS"));
+    }
+    years.push(year);
+    years.sort_unstable();
+
+    let items = years
+        .iter()
+        .map(|year| format!("crate::year_{year}::DAYS"))
+        .collect::<Vec<_>>();
+    let single_line = format!("{head}&[{}];", items.join(", "));
+    let replacement = if single_line.len() <= 100 {
+        single_line
+    } else {
+        let items = items
+            .iter()
+            .map(|item| format!("    {item},\n"))
+            .collect::<String>();
+        format!("{head}&[\n{items}];")
+    };
+    lines.splice(start..=end, [replacement.as_str()]);
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB: &str = "pub mod cli;
+pub mod verify;
+pub mod year_2025;
+";
+
+    const REGISTRY: &str = "use crate::{Day, DynSolution, Error, Input, Year};
+
+pub const YEARS: &[&[Entry]] = &[crate::year_2025::DAYS];
+
+pub fn entries() {}
+";
+
+    const YEAR: &str = "pub mod day_1;
+pub mod day_10;
+pub mod day_2;
+
+pub const DAYS: &[Entry] = &[
+    Entry::new(YEAR, day_1::DAY, day_1::solution),
+    Entry::new(YEAR, day_2::DAY, day_2::solution),
+    Entry::new(YEAR, day_10::DAY, day_10::solution),
+];
+";
+
+    #[test]
+    fn register_day() {
+        let module = register_module(YEAR, "day", 3).unwrap();
+        assert_eq!(
+            vec!["pub mod day_10;", "pub mod day_2;", "pub mod day_3;", ""],
+            module.lines().skip(1).take(4).collect::<Vec<_>>()
+        );
+        assert!(register_module(YEAR, "day", 10).is_err());
+        assert!(register_module(LIB, "day", 1).is_err());
+
+        let module = register_entry(YEAR, 3).unwrap();
+        assert_eq!(
+            "    Entry::new(YEAR, day_3::DAY, day_3::solution),",
+            module.lines().nth(7).unwrap()
+        );
+        let module = register_entry(YEAR, 11).unwrap();
+        assert!(module.lines().nth(8).unwrap().contains("day_11"));
+        assert!(register_entry(YEAR, 2).is_err());
+    }
+
+    #[test]
+    fn register_new_year() {
+        let registry = register_year(REGISTRY, 2024).unwrap();
+        assert_eq!(
+            "pub const YEARS: &[&[Entry]] = &[crate::year_2024::DAYS, crate::year_2025::DAYS];",
+            registry.lines().nth(2).unwrap()
+        );
+        assert!(register_year(REGISTRY, 2025).is_err());
+
+        let registry = register_year(&registry, 2026).unwrap();
+        let expected = "pub const YEARS: &[&[Entry]] = &[
+    crate::year_2024::DAYS,
+    crate::year_2025::DAYS,
+    crate::year_2026::DAYS,
+];";
+        assert!(registry.contains(expected), "{registry}");
+        assert!(registry.ends_with("pub fn entries() {}\n"));
+        assert!(register_year(&registry, 2027)
+            .unwrap()
+            .contains("year_2027::DAYS,\n];"));
+    }
+
+    #[test]
+    fn refuse_existing_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let src = root.join("src");
+        fs::create_dir_all(src.join("year_2025")).unwrap();
+        fs::write(src.join("lib.rs"), LIB).unwrap();
+        fs::write(src.join("registry.rs"), REGISTRY).unwrap();
+        fs::write(src.join("year_2025").join("mod.rs"), YEAR).unwrap();
+
+        let changed = new_day(&root, 2025, 3).unwrap();
+        assert_eq!(5, changed.len());
+        assert_eq!(
+            template(2025, 3),
+            read(&src.join("year_2025").join("day_3.rs")).unwrap()
+        );
+        assert!(root.join("inputs/2025/3/input.sample").exists());
+        assert!(new_day(&root, 2025, 3).is_err());
+        assert!(new_day(&root, 2025, 1).is_err());
+        assert!(!root.join("inputs/2025/1").exists());
+
+        let changed = new_day(&root, 2026, 1).unwrap();
+        assert_eq!(7, changed.len());
+        assert!(read(&src.join("lib.rs"))
+            .unwrap()
+            .contains("pub mod year_2026;"));
+        assert!(read(&src.join("registry.rs"))
+            .unwrap()
+            .contains("year_2026::DAYS"));
+        assert_eq!(
+            fill(YEAR_TEMPLATE, 2026, 1),
+            read(&src.join("year_2026").join("mod.rs")).unwrap()
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
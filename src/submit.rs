@@ -0,0 +1,313 @@
+use crate::fetch::Client;
+use crate::{Day, Part, Year};
+use std::fmt::Display;
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+
+/// How Advent of Code responded to a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Accepted,
+    TooHigh,
+    TooLow,
+    Wrong,
+}
+
+impl Verdict {
+    /// Read the verdict from the HTML of the response page. Responses that say nothing
+    /// about the answer, such as being told to wait before trying again, are errors.
+    fn from_response(html: &str) -> Result<Self, String> {
+        if html.contains("That's the right answer") {
+            Ok(Verdict::Accepted)
+        } else if html.contains("your answer is too high") {
+            Ok(Verdict::TooHigh)
+        } else if html.contains("your answer is too low") {
+            Ok(Verdict::TooLow)
+        } else if html.contains("That's not the right answer") {
+            Ok(Verdict::Wrong)
+        } else if html.contains("You gave an answer too recently") {
+            Err("An answer was submitted too recently, try again later".to_string())
+        } else if html.contains("You don't seem to be solving the right level") {
+            Err("This part is already solved, or part 1 has not been solved yet".to_string())
+        } else {
+            Err("Could not find a verdict in the response".to_string())
+        }
+    }
+
+    /// The name of the verdict in the ledger file.
+    fn key(self) -> &'static str {
+        match self {
+            Verdict::Accepted => "accepted",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::Wrong => "wrong",
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.key().replace('_', " "))
+    }
+}
+
+impl std::str::FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "accepted" => Ok(Verdict::Accepted),
+            "too_high" => Ok(Verdict::TooHigh),
+            "too_low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            _ => Err(format!("Unknown verdict: {s}")),
+        }
+    }
+}
+
+/// A single answer that was submitted, and what Advent of Code made of it.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Every answer submitted for a day, stored as tab separated `part verdict answer` lines
+/// in `inputs/{year}/{day}/submissions.tsv`.
+#[derive(Debug)]
+pub struct Ledger {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+
+impl Ledger {
+    /// Load the ledger from the day's directory. A day without a ledger has no submissions.
+    pub fn load(dir: &Path) -> Result<Self, String> {
+        let path = dir.join("submissions.tsv");
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(format!("{}: {err}", path.display())),
+        };
+
+        let mut submissions = Vec::new();
+        for (i, line) in contents.lines().enumerate() {
+            let invalid = |reason: &str| format!("{} line {}: {reason}", path.display(), i + 1);
+            let mut fields = line.splitn(3, '\t');
+            let (Some(part), Some(verdict), Some(answer)) =
+                (fields.next(), fields.next(), fields.next())
+            else {
+                return Err(invalid("Expected 'part verdict answer'"));
+            };
+            let part = match part {
+                "1" => Part::One,
+                "2" => Part::Two,
+                _ => return Err(invalid("Part must be 1 or 2")),
+            };
+            submissions.push(Submission {
+                part,
+                answer: answer.to_string(),
+                verdict: verdict.parse().map_err(|err: String| invalid(&err))?,
+            });
+        }
+        Ok(Self { path, submissions })
+    }
+
+    pub fn submissions(&self) -> &[Submission] {
+        &self.submissions
+    }
+
+    /// Whether an answer to the part has been accepted.
+    pub fn is_solved(&self, part: Part) -> bool {
+        self.submissions
+            .iter()
+            .any(|s| s.part == part && s.verdict == Verdict::Accepted)
+    }
+
+    /// Check the ledger for a reason not to submit the answer: the part has already been
+    /// solved, or the answer is known to be wrong. Numeric answers are also compared with
+    /// any answers that were too high or too low.
+    pub fn check(&self, part: Part, answer: &str) -> Result<(), String> {
+        let number = answer.parse::<i128>().ok();
+        for submission in self.submissions.iter().filter(|s| s.part == part) {
+            let known = submission.answer.parse::<i128>().ok();
+            let reason = match submission.verdict {
+                Verdict::Accepted => {
+                    format!("Part {part} was already solved with {}", submission.answer)
+                }
+                _ if submission.answer == answer => {
+                    format!(
+                        "{answer} was already submitted and was {}",
+                        submission.verdict
+                    )
+                }
+                Verdict::TooHigh if number.zip(known).is_some_and(|(n, k)| n >= k) => {
+                    format!("{answer} is too high, {} already was", submission.answer)
+                }
+                Verdict::TooLow if number.zip(known).is_some_and(|(n, k)| n <= k) => {
+                    format!("{answer} is too low, {} already was", submission.answer)
+                }
+                _ => continue,
+            };
+            return Err(reason);
+        }
+        Ok(())
+    }
+
+    fn record(&mut self, submission: Submission) -> Result<(), String> {
+        let line = format!(
+            "{}\t{}\t{}\n",
+            submission.part,
+            submission.verdict.key(),
+            submission.answer
+        );
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| file.write_all(line.as_bytes()))
+            .map_err(|err| format!("{}: {err}", self.path.display()))?;
+        self.submissions.push(submission);
+        Ok(())
+    }
+}
+
+/// Submit the answer to one part of the day, unless the ledger already knows it is wrong,
+/// and record the [`Verdict`] in the ledger.
+pub fn submit(
+    client: &dyn Client,
+    ledger: &mut Ledger,
+    year: Year,
+    day: Day,
+    part: Part,
+    answer: &str,
+) -> Result<Verdict, String> {
+    let level = match part {
+        Part::One => "1",
+        Part::Two => "2",
+        Part::Both => return Err("Only one part can be submitted at a time".to_string()),
+    };
+    if answer.is_empty() || answer.contains(['\t', '\n', '\r']) {
+        return Err(format!("{answer:?} is not a valid answer"));
+    }
+    ledger.check(part, answer)?;
+
+    let response = client.post(
+        &format!("/{year}/day/{day}/answer"),
+        &[("level", level), ("answer", answer)],
+    )?;
+    let verdict = Verdict::from_response(&response)?;
+    ledger.record(Submission {
+        part,
+        answer: answer.to_string(),
+        verdict,
+    })?;
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fetch::tests::{requests, serve};
+    use crate::fetch::Curl;
+    use std::cell::RefCell;
+
+    /// Responds to every answer with the next canned response, and records each post.
+    struct StandIn {
+        responses: RefCell<Vec<&'static str>>,
+        posts: RefCell<Vec<String>>,
+    }
+
+    impl Client for StandIn {
+        fn get(&self, path: &str) -> Result<String, String> {
+            Err(format!("404 Not Found: {path}"))
+        }
+
+        fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, String> {
+            let form = form.iter().map(|(key, value)| format!("{key}={value}"));
+            let form = form.collect::<Vec<_>>().join("&");
+            self.posts.borrow_mut().push(format!("{path}?{form}"));
+            Ok(self.responses.borrow_mut().remove(0).to_string())
+        }
+    }
+
+    #[test]
+    fn submit_and_record() {
+        let client = StandIn {
+            responses: RefCell::new(vec![
+                "<p>That's not the right answer; your answer is too high.</p>",
+                "<p>That's not the right answer.</p>",
+                "<p>You gave an answer too recently; you have to wait</p>",
+                "<p>That's the right answer! You are one gold star closer.</p>",
+            ]),
+            posts: RefCell::new(Vec::new()),
+        };
+        let dir = std::env::temp_dir().join(format!("aoc-submit-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let mut ledger = Ledger::load(&dir).unwrap();
+        let mut submit = |part, answer| submit(&client, &mut ledger, 2025, 1, part, answer);
+
+        assert_eq!(Ok(Verdict::TooHigh), submit(Part::One, "100"));
+        assert!(submit(Part::One, "100").is_err());
+        assert!(submit(Part::One, "150").is_err());
+        assert_eq!(Ok(Verdict::Wrong), submit(Part::One, "50"));
+        assert!(submit(Part::One, "50").is_err());
+        assert!(submit(Part::One, "40").is_err());
+        assert_eq!(Ok(Verdict::Accepted), submit(Part::One, "40"));
+        assert!(submit(Part::One, "41").is_err());
+        assert!(submit(Part::Both, "41").is_err());
+
+        let ledger = Ledger::load(&dir).unwrap();
+        let verdicts = ledger.submissions().iter().map(|s| s.verdict);
+        assert_eq!(
+            vec![Verdict::TooHigh, Verdict::Wrong, Verdict::Accepted],
+            verdicts.collect::<Vec<_>>()
+        );
+        assert_eq!(
+            "/2025/day/1/answer?level=1&answer=100",
+            client.posts.borrow()[0]
+        );
+        assert_eq!(4, client.posts.borrow().len());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn submit_with_curl() {
+        serve(
+            "POST /2025/day/5/answer level=2&answer=3%2C4",
+            "<main><article><p>That's not the right answer; your answer is too low. \
+             Please wait one minute before trying again.</p></article></main>",
+        );
+        let url = serve(
+            "POST /2025/day/5/answer level=2&answer=12",
+            "<main><article><p>That's the right answer! You are <span class=\"gold\">one \
+             gold star</span> closer to decorating the North Pole.</p></article></main>",
+        );
+        let client = Curl::new(&url, "secret");
+        let dir = std::env::temp_dir().join(format!("aoc-submit-curl-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let mut ledger = Ledger::load(&dir).unwrap();
+
+        let verdict = submit(&client, &mut ledger, 2025, 5, Part::Two, "3,4");
+        assert_eq!(Ok(Verdict::TooLow), verdict);
+        let verdict = submit(&client, &mut ledger, 2025, 5, Part::Two, "12");
+        assert_eq!(Ok(Verdict::Accepted), verdict);
+        let err = submit(&client, &mut ledger, 2025, 6, Part::One, "1").unwrap_err();
+        assert!(err.contains("404"), "{err}");
+
+        assert_eq!(
+            vec![
+                "POST /2025/day/5/answer level=2&answer=3%2C4",
+                "POST /2025/day/5/answer level=2&answer=12"
+            ],
+            requests("POST /2025/day/5/")
+        );
+        assert_eq!(
+            "2\ttoo_low\t3,4\n2\taccepted\t12\n",
+            fs::read_to_string(dir.join("submissions.tsv")).unwrap()
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
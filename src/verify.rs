@@ -0,0 +1,110 @@
+use crate::answers::Answers;
+use crate::registry::Entry;
+use crate::{Day, Error, InputKind, Part, Year};
+use std::fmt::Display;
+
+/// The outcome of checking one recorded answer.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    /// The answer is recorded, but the input it was computed from could not be loaded.
+    Missing(String),
+}
+
+/// A single recorded answer, and the result of checking it against the solution.
+#[derive(Debug)]
+pub struct Check {
+    pub year: Year,
+    pub day: Day,
+    pub input: InputKind,
+    pub part: Part,
+    pub status: Status,
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Check {
+            year,
+            day,
+            input,
+            part,
+            ..
+        } = self;
+        match &self.status {
+            Status::Pass => write!(f, "PASS    {year} Day {day} / Part {part} / {input}"),
+            Status::Fail { expected, actual } => write!(
+                f,
+                "FAIL    {year} Day {day} / Part {part} / {input}: expected {expected}, got {actual}"
+            ),
+            Status::Missing(reason) => {
+                write!(f, "MISSING {year} Day {day} / Part {part} / {input}: {reason}")
+            }
+        }
+    }
+}
+
+/// Run the day against every input that has a recorded answer, and compare the results.
+pub fn verify(entry: &Entry) -> Result<Vec<Check>, Error> {
+    let answers = Answers::load(entry.year, entry.day)?;
+
+    let mut checks = Vec::new();
+    let mut inputs = answers.iter().map(|(input, ..)| input).collect::<Vec<_>>();
+    inputs.dedup();
+    for input in inputs {
+        let solution = (entry.solution)(input.with_day(entry.year, entry.day));
+        for (_, part, expected) in answers.iter().filter(|(kind, ..)| *kind == input) {
+            let status = match &solution {
+                Err(err) => Status::Missing(err.to_string()),
+                Ok(solution) => {
+                    let actual = match part {
+                        Part::One => solution.part1(),
+                        Part::Two | Part::Both => solution.part2(),
+                    }
+                    .to_string();
+                    if actual == expected {
+                        Status::Pass
+                    } else {
+                        Status::Fail {
+                            expected: expected.to_string(),
+                            actual,
+                        }
+                    }
+                }
+            };
+            checks.push(Check {
+                year: entry.year,
+                day: entry.day,
+                input: input.clone(),
+                part,
+                status,
+            });
+        }
+    }
+    Ok(checks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    /// Check every day against every input with a recorded answer. The samples are part of
+    /// the repository, but the real inputs are skipped when they are not on this machine.
+    #[test]
+    fn recorded_answers() {
+        let failures = registry::entries()
+            .flat_map(|entry| verify(entry).unwrap())
+            .filter(|check| match check.status {
+                Status::Pass => false,
+                Status::Fail { .. } => true,
+                Status::Missing(_) => matches!(check.input, InputKind::Sample | InputKind::Sample2),
+            })
+            .map(|check| check.to_string())
+            .collect::<Vec<_>>();
+        assert!(failures.is_empty(), "{}", failures.join("\n"));
+    }
+}
@@ -0,0 +1,195 @@
+use crate::answers::Answers;
+use crate::{day_dir, input_dir, Day, InputKind, Part, Year};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// The answers from one run, keyed by the input and part they were solved for.
+pub type Answered = HashMap<(InputKind, Part), String>;
+
+const INPUTS: [InputKind; 4] = [
+    InputKind::Sample,
+    InputKind::Sample2,
+    InputKind::Part1,
+    InputKind::Part2,
+];
+
+/// Poll the day's source file and inputs, and each time they change rebuild the crate in
+/// `root` and solve the day against each of its inputs. The answers are printed along
+/// with how they differ from the previous run and from the recorded answers.
+pub fn watch(root: &Path, year: Year, day: Day, interval: Duration) -> Result<(), String> {
+    let source = root
+        .join("src")
+        .join(format!("year_{year}"))
+        .join(format!("day_{day}.rs"));
+    if !source.exists() {
+        return Err(format!("{} does not exist", source.display()));
+    }
+
+    let mut previous = Answered::new();
+    let mut last_seen = None;
+    loop {
+        let seen = modified(&source, &day_dir(year, day));
+        if last_seen.as_ref() != Some(&seen) {
+            last_seen = Some(seen);
+            println!("\nDay {day}: solving...");
+            let current = solve(root, year, day)?;
+            let golden = Answers::load(year, day).map_err(|err| err.to_string())?;
+            for line in diff(&current, &previous, &golden) {
+                println!("{line}");
+            }
+            previous = current;
+        }
+        thread::sleep(interval);
+    }
+}
+
+/// When the source and each file of the input directory were last modified.
+fn modified(source: &Path, inputs: &Path) -> Vec<(PathBuf, Option<SystemTime>)> {
+    let mut paths = vec![source.to_path_buf()];
+    if let Ok(entries) = fs::read_dir(inputs) {
+        paths.extend(entries.flatten().map(|entry| entry.path()));
+    }
+    paths.sort();
+    paths
+        .into_iter()
+        .map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+            (path, modified)
+        })
+        .collect()
+}
+
+/// Rebuild and run the day in a child process, once for each input file that exists.
+fn solve(root: &Path, year: Year, day: Day) -> Result<Answered, String> {
+    let cargo = std::env::var("CARGO").unwrap_or("cargo".to_string());
+    let mut answered = Answered::new();
+    for input in INPUTS {
+        let exists = input
+            .with_day(year, day)
+            .path()
+            .and_then(|path| fs::metadata(path).ok())
+            .is_some_and(|metadata| metadata.len() > 0);
+        if !exists {
+            continue;
+        }
+
+        let output = Command::new(&cargo)
+            .args(["run", "--quiet", "--release", "--manifest-path"])
+            .arg(root.join("Cargo.toml"))
+            .args(["--", "run", "--format", "csv"])
+            .args(["--year", &year.to_string(), "--day", &day.to_string()])
+            .args(["--input", &input.to_string(), "--input-dir"])
+            .arg(input_dir())
+            .stderr(Stdio::inherit())
+            .output()
+            .map_err(|err| format!("Could not run cargo: {err}"))?;
+        if !output.status.success() {
+            // The build failed, and cargo has already said why
+            return Ok(Answered::new());
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        for row in stdout.lines().skip(1).map(parse_csv_row) {
+            let part = match row.get(2).map(String::as_str) {
+                Some("1") => Part::One,
+                Some("2") => Part::Two,
+                _ => continue,
+            };
+            if let Some(answer) = row.get(4) {
+                answered.insert((input.clone(), part), answer.clone());
+            }
+        }
+    }
+    Ok(answered)
+}
+
+/// Split a row of the CSV output, undoing any quoting.
+fn parse_csv_row(line: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut chars = line.chars().peekable();
+    let mut quoted = false;
+    while let Some(ch) = chars.next() {
+        match ch {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                fields.last_mut().unwrap().push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(String::new()),
+            ch => fields.last_mut().unwrap().push(ch),
+        }
+    }
+    fields
+}
+
+/// Describe each answer, and how it compares with the previous run and the recorded answer.
+pub fn diff(current: &Answered, previous: &Answered, golden: &Answers) -> Vec<String> {
+    let mut lines = Vec::new();
+    for input in INPUTS {
+        for part in [Part::One, Part::Two] {
+            let key = (input.clone(), part);
+            let Some(answer) = current.get(&key) else {
+                continue;
+            };
+
+            let mut line = format!("{:<8} Part {part}: {answer}", input.to_string());
+            match previous.get(&key) {
+                Some(before) if before != answer => line += &format!(" (was {before})"),
+                Some(_) => {}
+                None if !previous.is_empty() => line += " (new)",
+                None => {}
+            }
+            match golden.get(&input, part) {
+                Some(expected) if expected == answer => line += " PASS",
+                Some(expected) => line += &format!(" FAIL, expected {expected}"),
+                None => {}
+            }
+            lines.push(line);
+        }
+    }
+    if lines.is_empty() {
+        lines.push("No answers".to_string());
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_rows() {
+        assert_eq!(
+            vec!["2025", "1", "2", "sample", "Say \"hi\", you", "20"],
+            parse_csv_row("2025,1,2,sample,\"Say \"\"hi\"\", you\",20")
+        );
+    }
+
+    #[test]
+    fn diff_answers() {
+        let golden = "[sample]\npart1 = 3\npart2 = 6".parse::<Answers>().unwrap();
+        let previous = Answered::from([((InputKind::Sample, Part::One), "2".to_string())]);
+        let current = Answered::from([
+            ((InputKind::Sample, Part::One), "3".to_string()),
+            ((InputKind::Sample, Part::Two), "7".to_string()),
+            ((InputKind::Part1, Part::One), "1165".to_string()),
+        ]);
+
+        assert_eq!(
+            vec![
+                "sample   Part 1: 3 (was 2) PASS",
+                "sample   Part 2: 7 (new) FAIL, expected 6",
+                "part1    Part 1: 1165 (new)",
+            ],
+            diff(&current, &previous, &golden)
+        );
+        assert_eq!(
+            vec!["sample   Part 1: 2"],
+            diff(&previous, &Answered::new(), &Answers::default())
+        );
+    }
+}
@@ -0,0 +1,129 @@
+use crate::{erase, read_to_string, DynSolution, Error, Input, Solution};
+use std::str::FromStr;
+
+pub const DAY: u8 = 1;
+
+#[derive(Debug)]
+enum Dial {
+    Left(i64),
+    Right(i64),
+}
+
+pub struct Day1(Vec<Dial>);
+
+impl Solution<usize, usize> for Day1 {
+    fn part1(&self) -> usize {
+        self.0
+            .iter()
+            .fold((0, 50), |(count, position), dial| {
+                let new_position = match dial {
+                    Dial::Left(v) => position - *v,
+                    Dial::Right(v) => position + *v,
+                }
+                .wrapping_rem_euclid(100);
+                let new_count = count + usize::from(new_position == 0);
+                (new_count, new_position)
+            })
+            .0
+    }
+
+    fn part2(&self) -> usize {
+        self.0
+            .iter()
+            .flat_map(|dial| match dial {
+                Dial::Right(v) => (0..*v).map(|_| 1).collect::<Vec<i64>>(),
+                Dial::Left(v) => (0..*v).map(|_| -1).collect(),
+            })
+            .fold((0, 50), |(count, position), dial| {
+                let new_position = (dial + position).wrapping_rem_euclid(100);
+                if new_position == 0 {
+                    (count + 1, new_position)
+                } else {
+                    (count, new_position)
+                }
+            })
+            .0
+    }
+}
+
+impl TryFrom<Input> for Day1 {
+    type Error = Error;
+
+    fn try_from(value: Input) -> Result<Self, Self::Error> {
+        read_to_string(&value)?.parse()
+    }
+}
+
+impl FromStr for Day1 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let instructions = s
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let line = line.trim();
+                let Some(direction) = line.chars().next() else {
+                    return Err(Error::invalid_line(i, "Expected a dial rotation"));
+                };
+                let dial = match direction {
+                    'L' => Dial::Left,
+                    'R' => Dial::Right,
+                    _ => return Err(Error::unexpected_char(i, 0, direction)),
+                };
+                let num = line[direction.len_utf8()..]
+                    .parse()
+                    .map_err(|_| Error::invalid_line(i, "Could not parse the rotation amount"))?;
+                Ok(dial(num))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Day1(instructions))
+    }
+}
+
+pub fn solution(input: Input) -> Result<Box<dyn DynSolution>, Error> {
+    Ok(erase(Day1::try_from(input)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::year_2025::YEAR;
+
+    #[test]
+    fn part_1() {
+        let day_sample = Day1::try_from(Input::Sample(YEAR, DAY)).unwrap();
+        assert_eq!(3, day_sample.part1());
+    }
+
+    #[test]
+    fn part_2() {
+        let day_sample = Day1::try_from(Input::Sample(YEAR, DAY)).unwrap();
+        assert_eq!(6, day_sample.part2());
+    }
+
+    #[test]
+    fn parse_inline() {
+        let day = "L68\nL30\nR48".parse::<Day1>().unwrap();
+        assert_eq!(1, day.part1());
+
+        let err = "L68\nX30".parse::<Day1>().err().unwrap();
+        assert!(matches!(
+            err,
+            Error::UnexpectedChar {
+                line: 2,
+                column: 1,
+                found: 'X'
+            }
+        ));
+
+        // The direction is checked before the amount, even when it is not ASCII
+        let err = "é5".parse::<Day1>().err().unwrap();
+        assert!(matches!(err, Error::UnexpectedChar { found: 'é', .. }));
+        let err = "Rx".parse::<Day1>().err().unwrap();
+        assert_eq!(
+            "Line 1: Could not parse the rotation amount",
+            err.to_string()
+        );
+    }
+}
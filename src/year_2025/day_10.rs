@@ -1,8 +1,12 @@
-use crate::{read_to_iter, Input, Solution};
+use crate::helper::parsing::{
+    braces, brackets, int, lines, map, pair, parens, parse, preceded, separated, take_while,
+    try_map, Parser,
+};
+use crate::{erase, read_to_string, DynSolution, Error, Input, Solution};
 use itertools::Itertools;
 use std::str::FromStr;
 
-const DAY: u8 = 10;
+pub const DAY: u8 = 10;
 
 type BitMask = u16;
 
@@ -87,44 +91,32 @@ impl Machine {
     }
 }
 
-impl From<String> for Machine {
-    fn from(value: String) -> Self {
-        let parts: Vec<&str> = value.split(' ').collect();
-        // [.##.] -> 0b0110 -> 6
-        let expected_state = {
-            let bytes = &parts[0].as_bytes()[1..parts[0].len() - 1];
-            let mut value = 0;
-            for (i, b) in bytes.iter().enumerate() {
-                if *b == b'#' {
-                    value |= 1 << i;
-                }
+/// A machine in the form `[.##.] (3) (1,3) (2) {3,5,4,7}`.
+fn machine<'a>() -> impl Parser<'a, Machine> {
+    // [.##.] -> 0b0110 -> 6
+    let lights = try_map(take_while(|ch| ch != ']'), |lights: &str| {
+        let mut value = 0;
+        for (i, ch) in lights.chars().enumerate() {
+            match ch {
+                '#' => value |= 1 << i,
+                '.' => {}
+                _ => return Err(format!("Unexpected indicator light {ch:?}")),
             }
-            value
-        };
-        // (3) (1,3) (2) (2,3) (0,2) (0,1)
-        let button_presses = parts[1..parts.len() - 1]
-            .iter()
-            .map(|tuple| {
-                tuple[1..tuple.len() - 1]
-                    .split(',')
-                    .flat_map(u16::from_str)
-                    .collect::<Vec<_>>()
-            })
-            .collect::<Vec<_>>();
-        // {3,5,4,7}
-        let joltage = {
-            let joltage_str = parts.last().unwrap();
-            joltage_str[1..joltage_str.len() - 1]
-                .split(',')
-                .flat_map(u16::from_str)
-                .collect::<Vec<u16>>()
-        };
-        Machine {
+        }
+        Ok(value)
+    });
+    let numbers = || separated(int::<u16>(), ",");
+    let buttons = preceded(" ", separated(parens(numbers()), " "));
+    let joltage = preceded(" ", braces(numbers()));
+
+    map(
+        pair(pair(brackets(lights), buttons), joltage),
+        |((expected_state, button_presses), joltage)| Machine {
             expected_state,
             button_presses,
             joltage,
-        }
-    }
+        },
+    )
 }
 
 pub struct Day10(Vec<Machine>);
@@ -142,33 +134,40 @@ impl Solution<usize, usize> for Day10 {
     }
 }
 
-impl From<Input> for Day10 {
-    fn from(value: Input) -> Self {
-        let machines = read_to_iter(&value).unwrap().map(Machine::from).collect();
-        Day10(machines)
+impl TryFrom<Input> for Day10 {
+    type Error = Error;
+
+    fn try_from(value: Input) -> Result<Self, Self::Error> {
+        read_to_string(&value)?.parse()
+    }
+}
+
+impl FromStr for Day10 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Day10(parse(s, lines(machine()))?))
     }
 }
 
-pub fn run() {
-    let day = Day10::from(Input::Part1(DAY));
-    day.report(DAY);
+pub fn solution(input: Input) -> Result<Box<dyn DynSolution>, Error> {
+    Ok(erase(Day10::try_from(input)?))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::year_2025::YEAR;
 
     #[test]
     fn part_1() {
-        let day_sample = Day10::from(Input::Sample(DAY));
+        let day_sample = Day10::try_from(Input::Sample(YEAR, DAY)).unwrap();
         assert_eq!(7, day_sample.part1());
-        assert_eq!(409, 409);
     }
 
     #[test]
     fn part_2() {
-        let day_sample = Day10::from(Input::Sample(DAY));
+        let day_sample = Day10::try_from(Input::Sample(YEAR, DAY)).unwrap();
         assert_eq!(33, day_sample.part2());
-        assert_eq!(15489, 15489);
     }
 }
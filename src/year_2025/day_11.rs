@@ -1,7 +1,9 @@
-use crate::{read_to_iter, Input, Solution};
+use crate::helper::parsing::{key_value, lines, parse, separated, word};
+use crate::{erase, read_to_string, DynSolution, Error, Input, Solution};
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::str::FromStr;
 
-const DAY: u8 = 11;
+pub const DAY: u8 = 11;
 
 type Device = String;
 type ConnectedDevices = Vec<Device>;
@@ -90,44 +92,50 @@ impl Solution<usize, i64> for Day11 {
     }
 }
 
-impl From<Input> for Day11 {
-    fn from(value: Input) -> Self {
-        let server = read_to_iter(&value)
-            .unwrap()
-            .map(|line| {
-                let (device, raw_connections) = line.split_once(": ").unwrap();
-                let connections = raw_connections
-                    .split(' ')
-                    .map(ToString::to_string)
-                    .collect::<Vec<_>>();
+impl TryFrom<Input> for Day11 {
+    type Error = Error;
+
+    fn try_from(value: Input) -> Result<Self, Self::Error> {
+        read_to_string(&value)?.parse()
+    }
+}
+
+impl FromStr for Day11 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // aaa: you hhh
+        let devices = parse(s, lines(key_value(word(), ": ", separated(word(), " "))))?;
+        let server = devices
+            .into_iter()
+            .map(|(device, connections)| {
+                let connections = connections.into_iter().map(ToString::to_string).collect();
                 (device.to_string(), connections)
             })
-            .collect::<HashMap<_, _>>();
+            .collect();
 
-        Day11(server)
+        Ok(Day11(server))
     }
 }
 
-pub fn run() {
-    let day = Day11::from(Input::Part1(DAY));
-    day.report(DAY);
+pub fn solution(input: Input) -> Result<Box<dyn DynSolution>, Error> {
+    Ok(erase(Day11::try_from(input)?))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::year_2025::YEAR;
 
     #[test]
     fn part_1() {
-        let day_sample = Day11::from(Input::Sample(DAY));
+        let day_sample = Day11::try_from(Input::Sample(YEAR, DAY)).unwrap();
         assert_eq!(5, day_sample.part1());
-        assert_eq!(749, 749);
     }
 
     #[test]
     fn part_2() {
-        let day_sample = Day11::from(Input::Sample2(DAY));
+        let day_sample = Day11::try_from(Input::Sample2(YEAR, DAY)).unwrap();
         assert_eq!(2, day_sample.part2());
-        assert_eq!(420_257_875_695_750, 420_257_875_695_750_i64);
     }
 }
@@ -0,0 +1,630 @@
+use crate::helper::parsing::{
+    int, key_value, line_end, lines, map, pair, parse_at, preceded, separated, take_while, try_map,
+};
+use crate::points::grid::Grid;
+use crate::points::point_2d::Point2D;
+use crate::{erase, read_to_string, split_sections, DynSolution, Error, Input, Section, Solution};
+use itertools::Itertools;
+use std::str::FromStr;
+
+pub const DAY: u8 = 12;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Cell {
+    Empty,
+    Filled,
+}
+
+impl TryFrom<char> for Cell {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '#' => Ok(Cell::Filled),
+            '.' => Ok(Cell::Empty),
+            _ => Err(()),
+        }
+    }
+}
+
+type Shape = Grid<Cell>;
+type Row = u128;
+
+/// The widest region the packer can fill, as each row of the region is a [`Row`] bitmask.
+/// Presents are turned, so they can be at most this many cells wide and tall.
+const MAX_WIDTH: usize = Row::BITS as usize;
+
+/// The labels of presents when drawing a region: letters, then digits, then symbols other
+/// than the `#` and `.` of the input.
+const LABELS: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789@$%&*+=?!~^<>";
+
+/// One rotation or flip of a present, as a bitmask per row with bit `c` set when column `c`
+/// is filled. The first row always has a filled cell, and `anchor` is the column of the
+/// first one.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Orientation {
+    rows: Vec<Row>,
+    width: usize,
+    anchor: usize,
+}
+
+impl Orientation {
+    fn new(cells: &[Point2D<i32>]) -> Self {
+        let min = cells
+            .iter()
+            .fold(cells[0], |min, &cell| min.component_min(cell));
+        let mut rows: Vec<Row> = Vec::new();
+        let mut width = 0;
+        for cell in cells.iter().map(|&cell| cell - min) {
+            let (row, col) = (cell.row() as usize, cell.col() as usize);
+            if rows.len() <= row {
+                rows.resize(row + 1, 0);
+            }
+            rows[row] |= 1 << col;
+            width = width.max(col + 1);
+        }
+        let anchor = rows[0].trailing_zeros() as usize;
+        Self {
+            rows,
+            width,
+            anchor,
+        }
+    }
+}
+
+/// A present, in every distinct orientation it can be placed in.
+#[derive(Debug)]
+struct Present {
+    cells: usize,
+    orientations: Vec<Orientation>,
+}
+
+impl Present {
+    fn new(shape: &Shape) -> Result<Self, String> {
+        let mut cells = shape
+            .iter::<i32>()
+            .filter(|(_, cell)| **cell == Cell::Filled)
+            .map(|(point, _)| point)
+            .collect::<Vec<_>>();
+        if cells.is_empty() {
+            return Err("A present must have at least one filled cell".to_string());
+        }
+        if shape.width().max(shape.height()) > MAX_WIDTH {
+            return Err(format!(
+                "A present can be at most {MAX_WIDTH} cells wide and tall"
+            ));
+        }
+
+        let mut orientations = Vec::new();
+        for _ in 0..4 {
+            cells = cells.iter().map(Point2D::turn_right).collect();
+            let flipped = cells
+                .iter()
+                .map(|cell| Point2D::new(cell.row(), -cell.col()))
+                .collect::<Vec<_>>();
+            orientations.push(Orientation::new(&cells));
+            orientations.push(Orientation::new(&flipped));
+        }
+        orientations.sort();
+        orientations.dedup();
+
+        Ok(Self {
+            cells: cells.len(),
+            orientations,
+        })
+    }
+}
+
+/// An orientation of a present placed in a region, with its first row at `row` and shifted
+/// `shift` columns to the right.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Placement {
+    id: usize,
+    orientation: usize,
+    row: usize,
+    shift: usize,
+}
+
+impl Placement {
+    /// The cells of the region the present covers.
+    fn cells(&self, presents: &[Present]) -> impl Iterator<Item = Point2D<i32>> {
+        let orientation = &presents[self.id].orientations[self.orientation];
+        orientation
+            .rows
+            .iter()
+            .enumerate()
+            .flat_map(move |(row, &mask)| {
+                (0..MAX_WIDTH)
+                    .filter(move |&col| mask & (1 << col) != 0)
+                    .map(move |col| {
+                        Point2D::new((self.row + row) as i32, (self.shift + col) as i32)
+                    })
+            })
+    }
+}
+
+/// Why the presents of a region were not packed.
+#[derive(Debug, PartialEq, Eq)]
+enum Unpacked {
+    /// There is no way to place every present.
+    DoesNotFit,
+    /// The region is wider than [`MAX_WIDTH`], so the search cannot decide either way.
+    TooWide,
+}
+
+struct Region {
+    width: usize,
+    height: usize,
+    required_present_ids: Vec<usize>,
+}
+
+impl Region {
+    /// A way to place every required present in the region without overlapping. Regions that
+    /// are too wide for the search can still be settled by the checks that come first.
+    fn pack(&self, presents: &[Present], shapes: &[Shape]) -> Result<Vec<Placement>, Unpacked> {
+        // Presents no bigger than a box always fit when each can have a box to itself
+        let box_width = shapes.iter().map(Shape::width).max().unwrap_or(1).max(1);
+        let box_height = shapes.iter().map(Shape::height).max().unwrap_or(1).max(1);
+        let boxes = (self.width / box_width) * (self.height / box_height);
+        if self.required_present_ids.iter().sum::<usize>() <= boxes {
+            let columns = self.width / box_width;
+            let placements = self
+                .required_present_ids
+                .iter()
+                .enumerate()
+                .flat_map(|(id, &count)| std::iter::repeat_n(id, count))
+                .enumerate()
+                .map(|(index, id)| {
+                    // The shape as given fits in a box, so at least one orientation does
+                    let orientation = presents[id]
+                        .orientations
+                        .iter()
+                        .position(|o| o.width <= box_width && o.rows.len() <= box_height)
+                        .unwrap();
+                    Placement {
+                        id,
+                        orientation,
+                        row: index / columns * box_height,
+                        shift: index % columns * box_width,
+                    }
+                })
+                .collect();
+            return Ok(placements);
+        }
+
+        let cells = self
+            .required_present_ids
+            .iter()
+            .zip(presents)
+            .map(|(count, present)| count * present.cells)
+            .sum::<usize>();
+        let slack = (self.width * self.height)
+            .checked_sub(cells)
+            .ok_or(Unpacked::DoesNotFit)?;
+        if self.width > MAX_WIDTH {
+            return Err(Unpacked::TooWide);
+        }
+
+        let mut packer = Packer {
+            presents,
+            width: self.width,
+            height: self.height,
+            rows: vec![0; self.height],
+            remaining: self.required_present_ids.clone(),
+            slack,
+            placed: Vec::new(),
+        };
+        if packer.pack(0) {
+            Ok(packer.placed)
+        } else {
+            Err(Unpacked::DoesNotFit)
+        }
+    }
+
+    /// Check that the placements are exactly the required presents, each inside the region
+    /// and not overlapping any other.
+    fn validate(&self, placements: &[Placement], presents: &[Present]) -> Result<(), String> {
+        let mut covered = Grid::filled(self.width, self.height, None);
+        let mut counts = vec![0; presents.len()];
+        for (index, placement) in placements.iter().enumerate() {
+            counts[placement.id] += 1;
+            for cell in placement.cells(presents) {
+                let (row, col) = (cell.row(), cell.col());
+                match covered.get_mut(cell) {
+                    None => {
+                        return Err(format!(
+                            "Present {index} is outside the region at row {row}, column {col}"
+                        ));
+                    }
+                    Some(Some(other)) => {
+                        return Err(format!(
+                            "Presents {other} and {index} overlap at row {row}, column {col}"
+                        ));
+                    }
+                    Some(free) => *free = Some(index),
+                }
+            }
+        }
+        if counts != self.required_present_ids {
+            return Err(format!(
+                "Expected present counts {:?}, found {counts:?}",
+                self.required_present_ids
+            ));
+        }
+        Ok(())
+    }
+
+    /// Draw the region after checking the placements, with empty cells as `.` and each present
+    /// labelled from [`LABELS`]. The first 75 presents each have their own label. After that
+    /// labels are reused, so only presents that touch are sure to differ, and the layout can
+    /// be ambiguous.
+    fn draw(&self, placements: &[Placement], presents: &[Present]) -> Result<Grid<char>, String> {
+        self.validate(placements, presents)?;
+        let mut drawing = Grid::filled(self.width, self.height, '.');
+        for (index, placement) in placements.iter().enumerate() {
+            let cells = placement.cells(presents).collect::<Vec<_>>();
+            let touching = cells
+                .iter()
+                .flat_map(Point2D::neighbors4)
+                .filter_map(|cell| drawing.get(cell).copied())
+                .collect::<Vec<_>>();
+            let label = LABELS
+                .chars()
+                .cycle()
+                .skip(index % LABELS.len())
+                .take(LABELS.len())
+                .find(|label| !touching.contains(label))
+                .ok_or("Too many touching presents to label")?;
+            for cell in cells {
+                drawing[cell] = label;
+            }
+        }
+        Ok(drawing)
+    }
+}
+
+/// Backtracking search for a way to place the remaining presents.
+struct Packer<'a> {
+    presents: &'a [Present],
+    width: usize,
+    height: usize,
+    rows: Vec<Row>,
+    remaining: Vec<usize>,
+    /// How many more cells can be left empty.
+    slack: usize,
+    /// The presents placed so far, in the order they were placed.
+    placed: Vec<Placement>,
+}
+
+impl Packer<'_> {
+    /// Fill the region from `cell` onwards, counting cells row by row. The first free cell is
+    /// either covered by the first filled cell of a present, or left empty. As every present
+    /// is placed by its first cell, it only covers cells after that one, so the search never
+    /// needs to look back.
+    fn pack(&mut self, cell: usize) -> bool {
+        if self.remaining.iter().all(|&count| count == 0) {
+            return true;
+        }
+        let Some(cell) = (cell..self.width * self.height)
+            .find(|&cell| self.rows[cell / self.width] & (1 << (cell % self.width)) == 0)
+        else {
+            return false;
+        };
+        let (row, col) = (cell / self.width, cell % self.width);
+
+        for id in 0..self.presents.len() {
+            if self.remaining[id] == 0 {
+                continue;
+            }
+            for (index, orientation) in self.presents[id].orientations.iter().enumerate() {
+                let Some(shift) = self.shift(orientation, row, col) else {
+                    continue;
+                };
+                self.toggle(orientation, row, shift);
+                self.remaining[id] -= 1;
+                self.placed.push(Placement {
+                    id,
+                    orientation: index,
+                    row,
+                    shift,
+                });
+                let packed = self.pack(cell + 1);
+                self.remaining[id] += 1;
+                self.toggle(orientation, row, shift);
+                if packed {
+                    return true;
+                }
+                self.placed.pop();
+            }
+        }
+
+        if self.slack > 0 {
+            self.slack -= 1;
+            let packed = self.pack(cell + 1);
+            self.slack += 1;
+            return packed;
+        }
+        false
+    }
+
+    /// How far to shift the orientation so that its anchor is at the cell, if it then fits
+    /// inside the region without overlapping anything.
+    fn shift(&self, orientation: &Orientation, row: usize, col: usize) -> Option<usize> {
+        let shift = col.checked_sub(orientation.anchor)?;
+        if shift + orientation.width > self.width || row + orientation.rows.len() > self.height {
+            return None;
+        }
+        orientation
+            .rows
+            .iter()
+            .zip(&self.rows[row..])
+            .all(|(mask, filled)| (mask << shift) & filled == 0)
+            .then_some(shift)
+    }
+
+    /// Place the orientation, or remove it if it was placed.
+    fn toggle(&mut self, orientation: &Orientation, row: usize, shift: usize) {
+        for (filled, mask) in self.rows[row..].iter_mut().zip(&orientation.rows) {
+            *filled ^= mask << shift;
+        }
+    }
+}
+
+pub struct Day12 {
+    shapes: Vec<Shape>,
+    presents: Vec<Present>,
+    regions: Vec<Region>,
+}
+
+impl Day12 {
+    /// Build from a section per present shape, followed by the section of regions.
+    fn from_sections(sections: impl Iterator<Item = Section>) -> Result<Self, Error> {
+        let mut sections = sections.collect::<Vec<_>>();
+        let regions = sections
+            .pop()
+            .ok_or_else(|| Error::invalid_line(0, "Expected shapes and regions"))?;
+
+        let shapes = sections
+            .iter()
+            .enumerate()
+            .map(|(id, section)| {
+                // 0:
+                // ###
+                // ##.
+                let header = map(
+                    pair(int::<usize>(), preceded(":", line_end())),
+                    |(id, _)| id,
+                );
+                let rows = lines(take_while(|ch| ch == '#' || ch == '.'));
+                let (found, rows) = parse_at(section.line, &section.text, pair(header, rows))?;
+                if found != id {
+                    return Err(Error::invalid_line(
+                        section.line,
+                        format!("Expected shape {id}, found {found}"),
+                    ));
+                }
+                rows.join("\n").parse::<Shape>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let presents = shapes
+            .iter()
+            .zip(&sections)
+            .map(|(shape, section)| {
+                Present::new(shape).map_err(|reason| Error::invalid_line(section.line, reason))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // 12x5: 1 0 1 0 2 2
+        let size = pair(int::<usize>(), preceded("x", int::<usize>()));
+        let counts = try_map(separated(int::<usize>(), " "), |counts: Vec<usize>| {
+            if counts.len() == shapes.len() {
+                Ok(counts)
+            } else {
+                Err(format!("Expected {} present counts", shapes.len()))
+            }
+        });
+        let region = map(
+            key_value(size, ": ", counts),
+            |((width, height), counts)| Region {
+                width,
+                height,
+                required_present_ids: counts,
+            },
+        );
+        let regions = parse_at(regions.line, &regions.text, lines(region))?;
+
+        Ok(Day12 {
+            shapes,
+            presents,
+            regions,
+        })
+    }
+}
+
+impl Solution<usize, &'static str> for Day12 {
+    fn part1(&self) -> usize {
+        self.regions
+            .iter()
+            .filter(|region| region.pack(&self.presents, &self.shapes).is_ok())
+            .count()
+    }
+
+    fn part2(&self) -> &'static str {
+        "Finish Decorating the North Pole!"
+    }
+
+    /// Each region the presents fit in, headed as in the input, or [`None`] if there are none.
+    fn visualize(&self) -> Option<String> {
+        let drawings = self
+            .regions
+            .iter()
+            .filter_map(|region| {
+                let placements = region.pack(&self.presents, &self.shapes).ok()?;
+                let header = format!(
+                    "{}x{}: {}",
+                    region.width,
+                    region.height,
+                    region.required_present_ids.iter().join(" ")
+                );
+                Some(match region.draw(&placements, &self.presents) {
+                    Ok(drawing) => format!("{header}\n{drawing}"),
+                    Err(err) => format!("{header}\nInvalid layout: {err}\n"),
+                })
+            })
+            .collect::<Vec<_>>();
+        (!drawings.is_empty()).then(|| drawings.join("\n"))
+    }
+}
+
+impl TryFrom<Input> for Day12 {
+    type Error = Error;
+
+    fn try_from(value: Input) -> Result<Self, Self::Error> {
+        read_to_string(&value)?.parse()
+    }
+}
+
+impl FromStr for Day12 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Day12::from_sections(split_sections(s))
+    }
+}
+
+pub fn solution(input: Input) -> Result<Box<dyn DynSolution>, Error> {
+    Ok(erase(Day12::try_from(input)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::points::point_2d::Point2D;
+    use crate::year_2025::YEAR;
+
+    #[test]
+    fn part_1() {
+        let day_sample = Day12::try_from(Input::Sample(YEAR, DAY)).unwrap();
+        assert_eq!(2, day_sample.part1());
+    }
+
+    #[test]
+    fn parse_shapes() {
+        let day_sample = Day12::try_from(Input::Sample(YEAR, DAY)).unwrap();
+        assert_eq!(6, day_sample.shapes.len());
+        assert_eq!(Cell::Empty, day_sample.shapes[4][Point2D::<i8>::new(1, 1)]);
+        assert_eq!(3, day_sample.regions.len());
+        assert_eq!(7, day_sample.presents[4].cells);
+        // The C shape of present 4 looks the same when flipped
+        assert_eq!(4, day_sample.presents[4].orientations.len());
+        assert_eq!(8, day_sample.presents[0].orientations.len());
+
+        let err = "0:\n#x\n\n1x1: 1".parse::<Day12>().err().unwrap();
+        assert_eq!("Line 2, column 2: Unexpected \"x\"", err.to_string());
+        let err = "0:\n#\n\n1x1: 1 2".parse::<Day12>().err().unwrap();
+        assert_eq!(
+            "Line 4, column 6: Expected 1 present counts",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn shapes_with_crlf() {
+        let crlf = "0:\r\n#.\r\n##\r\n\r\n1:\r\n#\r\n\r\n2x2: 1 1\r\n1x1: 1 0\r\n";
+        let day = crlf.parse::<Day12>().unwrap();
+        assert_eq!((2, 2), (day.shapes.len(), day.regions.len()));
+        assert_eq!(1, day.part1());
+    }
+
+    #[test]
+    fn regions_wider_than_the_packer() {
+        let day = "0:\n#.\n##\n\n201x2: 100\n201x2: 101\n3x1: 2"
+            .parse::<Day12>()
+            .unwrap();
+        let pack = |region: &Region| {
+            let placements = region.pack(&day.presents, &day.shapes);
+            placements.map(|placements| placements.len())
+        };
+        assert_eq!(Ok(100), pack(&day.regions[0]));
+        assert_eq!(Err(Unpacked::TooWide), pack(&day.regions[1]));
+        assert_eq!(Err(Unpacked::DoesNotFit), pack(&day.regions[2]));
+        assert_eq!(1, day.part1());
+
+        let wide = format!("0:\n{}\n\n1x1: 0", "#".repeat(MAX_WIDTH + 1));
+        let err = wide.parse::<Day12>().err().unwrap();
+        assert_eq!(
+            "Line 1: A present can be at most 128 cells wide and tall",
+            err.to_string()
+        );
+    }
+
+    #[test]
+    fn draw_layouts() {
+        let day_sample = Day12::try_from(Input::Sample(YEAR, DAY)).unwrap();
+        let visualization = day_sample.visualize().unwrap();
+        assert!(visualization.starts_with("4x4: 0 0 0 0 2 0\n"));
+        assert!(visualization.contains("\n\n12x5: 1 0 1 0 2 2\n"));
+        assert!(!visualization.contains("Invalid"));
+        assert!(!visualization.contains("1 0 1 0 3 2"));
+
+        let region = &day_sample.regions[1];
+        let placements = region
+            .pack(&day_sample.presents, &day_sample.shapes)
+            .unwrap();
+        assert_eq!(6, placements.len());
+        let drawing = region.draw(&placements, &day_sample.presents).unwrap();
+        let empty = drawing
+            .iter::<i32>()
+            .filter(|(_, cell)| **cell == '.')
+            .count();
+        assert_eq!(60 - 42, empty);
+        let labels = drawing.iter::<i32>().map(|(_, cell)| *cell).unique();
+        assert_eq!(".ABCDEF", labels.sorted().collect::<String>());
+    }
+
+    #[test]
+    fn draw_runs_out_of_labels() {
+        let day = "0:\n#\n\n9x9: 80\n1x1: 2".parse::<Day12>().unwrap();
+        let visualization = day.visualize().unwrap();
+        let drawing = visualization.lines().skip(1).collect::<String>();
+        let labels = drawing.chars().filter(|&cell| cell != '.').unique();
+        assert_eq!(LABELS.len(), labels.count());
+        assert!(!drawing.contains('#'));
+        assert!(!visualization.contains("1x1"));
+
+        let day = "0:\n#\n\n1x1: 2".parse::<Day12>().unwrap();
+        assert_eq!(None, day.visualize());
+    }
+
+    #[test]
+    fn validate_placements() {
+        let day_sample = Day12::try_from(Input::Sample(YEAR, DAY)).unwrap();
+        let (region, presents) = (&day_sample.regions[0], &day_sample.presents);
+        let placements = region.pack(presents, &day_sample.shapes).unwrap();
+        assert_eq!(Ok(()), region.validate(&placements, presents));
+
+        let overlapping = [placements[0], placements[0]];
+        assert_eq!(
+            Err("Presents 0 and 1 overlap at row 0, column 0".to_string()),
+            region.validate(&overlapping, presents)
+        );
+        let outside = [Placement {
+            row: 2,
+            ..placements[0]
+        }];
+        assert!(region
+            .validate(&outside, presents)
+            .unwrap_err()
+            .starts_with("Present 0 is outside the region at row 4"));
+        assert_eq!(
+            Err("Expected present counts [0, 0, 0, 0, 2, 0], found [0, 0, 0, 0, 1, 0]".to_string()),
+            region.validate(&placements[..1], presents)
+        );
+    }
+
+    #[test]
+    fn part_2() {
+        let day_sample = Day12::try_from(Input::Sample(YEAR, DAY)).unwrap();
+        assert_eq!("Finish Decorating the North Pole!", day_sample.part2());
+    }
+}
@@ -1,20 +1,21 @@
-use crate::{helper, Input, Solution};
+use crate::helper::parsing::{parse, range, separated};
+use crate::helper::range_set::RangeSet;
+use crate::{erase, read_to_string, DynSolution, Error, Input, Solution};
+use std::str::FromStr;
 
-const DAY: u8 = 2;
+pub const DAY: u8 = 2;
 
-type Ranges = Vec<(usize, usize)>;
+type Ranges = RangeSet<usize>;
 
 pub struct Day2(Ranges);
 
 impl Day2 {
+    /// Sum the IDs that pass the filter. The ranges are merged as they are read, so an ID in
+    /// more than one of the input's ranges is only counted once.
     fn solution(&self, filter_fn: fn(usize) -> bool) -> usize {
         self.0
             .iter()
-            .map(|&(start, end)| {
-                (start..=end)
-                    .filter(|&value| filter_fn(value))
-                    .sum::<usize>()
-            })
+            .map(|range| range.filter(|&value| filter_fn(value)).sum::<usize>())
             .sum()
     }
 }
@@ -29,14 +30,20 @@ impl Solution<usize, usize> for Day2 {
     }
 }
 
-impl From<Input> for Day2 {
-    fn from(value: Input) -> Self {
-        let contents = std::fs::read_to_string(value.to_string()).unwrap();
-        let ranges = contents
-            .split(',')
-            .map(helper::parsing::line_to_range_tuple::<&str, usize>)
-            .collect::<Vec<_>>();
-        Day2(ranges)
+impl TryFrom<Input> for Day2 {
+    type Error = Error;
+
+    fn try_from(value: Input) -> Result<Self, Self::Error> {
+        read_to_string(&value)?.parse()
+    }
+}
+
+impl FromStr for Day2 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let ranges = parse(s, separated(range(), ","))?;
+        Ok(Day2(ranges.into_iter().collect()))
     }
 }
 
@@ -67,26 +74,31 @@ fn has_repetition_at_least_twice(value: usize) -> bool {
         })
 }
 
-pub fn run() {
-    let day = Day2::from(Input::Part1(DAY));
-    day.report(DAY);
+pub fn solution(input: Input) -> Result<Box<dyn DynSolution>, Error> {
+    Ok(erase(Day2::try_from(input)?))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::year_2025::YEAR;
 
     #[test]
     fn part_1() {
-        let day_sample = Day2::from(Input::Sample(DAY));
+        let day_sample = Day2::try_from(Input::Sample(YEAR, DAY)).unwrap();
         assert_eq!(1_227_775_554, day_sample.part1());
-        assert_eq!(37_314_786_486_usize, 37_314_786_486_usize);
     }
 
     #[test]
     fn part_2() {
-        let day_sample = Day2::from(Input::Sample(DAY));
+        let day_sample = Day2::try_from(Input::Sample(YEAR, DAY)).unwrap();
         assert_eq!(4_174_379_265, day_sample.part2());
-        assert_eq!(47_477_054_026, 47_477_054_026usize);
+    }
+
+    #[test]
+    fn overlapping_ranges_count_once() {
+        // 22 and 33 are in both ranges
+        let day = "1-30,20-40".parse::<Day2>().unwrap();
+        assert_eq!(11 + 22 + 33, day.part1());
     }
 }
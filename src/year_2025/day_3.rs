@@ -1,6 +1,7 @@
-use crate::{read_to_iter, Input, Solution};
+use crate::{erase, read_to_string, DynSolution, Error, Input, Solution};
+use std::str::FromStr;
 
-const DAY: u8 = 3;
+pub const DAY: u8 = 3;
 
 type Battery = Vec<usize>;
 type BatteryBanks = Vec<Battery>;
@@ -26,25 +27,38 @@ impl Solution<usize, usize> for Day3 {
     }
 }
 
-impl From<Input> for Day3 {
-    fn from(value: Input) -> Self {
-        let iter = read_to_iter(&value)
-            .unwrap()
-            .map(|line| {
-                let bytes = line.as_bytes();
-                bytes
-                    .iter()
-                    .map(move |b| (b - b'0') as usize)
-                    .collect::<Vec<_>>()
+impl TryFrom<Input> for Day3 {
+    type Error = Error;
+
+    fn try_from(value: Input) -> Result<Self, Self::Error> {
+        read_to_string(&value)?.parse()
+    }
+}
+
+impl FromStr for Day3 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let iter = s
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(col, ch)| {
+                        ch.to_digit(10)
+                            .map(|digit| digit as usize)
+                            .ok_or(Error::unexpected_char(i, col, ch))
+                    })
+                    .collect::<Result<Vec<_>, _>>()
             })
-            .collect();
-        Day3(iter)
+            .collect::<Result<_, _>>()?;
+        Ok(Day3(iter))
     }
 }
 
-pub fn run() {
-    let day = Day3::from(Input::Part1(DAY));
-    day.report(DAY);
+pub fn solution(input: Input) -> Result<Box<dyn DynSolution>, Error> {
+    Ok(erase(Day3::try_from(input)?))
 }
 
 fn find_next_largest(slice: &[usize], offset: usize, limit: usize) -> (usize, usize) {
@@ -87,19 +101,18 @@ fn find_max_joltage(slice: &[usize], length: usize) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::year_2025::YEAR;
 
     #[test]
     fn part_1() {
-        let day_sample = Day3::from(Input::Sample(DAY));
+        let day_sample = Day3::try_from(Input::Sample(YEAR, DAY)).unwrap();
         assert_eq!(357, day_sample.part1());
-        assert_eq!(17613, 17613);
     }
 
     #[test]
     fn part_2() {
-        let day_sample = Day3::from(Input::Sample(DAY));
+        let day_sample = Day3::try_from(Input::Sample(YEAR, DAY)).unwrap();
         assert_eq!(3_121_910_778_619, day_sample.part2());
-        assert_eq!(175_304_218_462_560, 175_304_218_462_560_usize);
     }
 
     #[test]
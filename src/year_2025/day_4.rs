@@ -0,0 +1,113 @@
+use crate::points::grid::Grid;
+use crate::points::point_2d::Point2D;
+use crate::{erase, read_to_string, DynSolution, Error, Input, Solution};
+use std::collections::HashSet;
+use std::str::FromStr;
+
+pub const DAY: u8 = 4;
+
+type PaperRolls = Point2D<i16>;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Empty,
+    PaperRoll,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '@' => Ok(Tile::PaperRoll),
+            '.' => Ok(Tile::Empty),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Day4(HashSet<PaperRolls>);
+
+impl Day4 {
+    fn reachable_paper_rolls(&self) -> HashSet<PaperRolls> {
+        self.0
+            .iter()
+            .filter(|&point| {
+                let adjacent_rolls = point
+                    .neighbors8()
+                    .iter()
+                    .filter(|neighbor| self.0.contains(neighbor))
+                    .count();
+                adjacent_rolls < 4
+            })
+            .copied()
+            .collect()
+    }
+}
+
+impl Solution<usize, usize> for Day4 {
+    fn part1(&self) -> usize {
+        self.reachable_paper_rolls().len()
+    }
+
+    fn part2(&self) -> usize {
+        let mut points = self.clone();
+
+        let mut remove_count = 0;
+        let mut reachable = points.reachable_paper_rolls();
+        while !reachable.is_empty() {
+            for point in &reachable {
+                points.0.remove(point);
+            }
+            remove_count += reachable.len();
+            reachable = points.reachable_paper_rolls();
+        }
+
+        remove_count
+    }
+}
+
+impl TryFrom<Input> for Day4 {
+    type Error = Error;
+
+    fn try_from(value: Input) -> Result<Self, Self::Error> {
+        read_to_string(&value)?.parse()
+    }
+}
+
+impl FromStr for Day4 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = s.parse::<Grid<Tile>>()?;
+        let points = grid
+            .iter()
+            .filter(|(_, tile)| **tile == Tile::PaperRoll)
+            .map(|(point, _)| point)
+            .collect();
+        Ok(Day4(points))
+    }
+}
+
+pub fn solution(input: Input) -> Result<Box<dyn DynSolution>, Error> {
+    Ok(erase(Day4::try_from(input)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::year_2025::YEAR;
+
+    #[test]
+    fn part_1() {
+        let day_sample = Day4::try_from(Input::Sample(YEAR, DAY)).unwrap();
+        assert_eq!(13, day_sample.part1());
+    }
+
+    #[test]
+    fn part_2() {
+        let day_sample = Day4::try_from(Input::Sample(YEAR, DAY)).unwrap();
+        assert_eq!(43, day_sample.part2());
+    }
+}
@@ -0,0 +1,118 @@
+use crate::helper::parsing::{int, lines, parse_at, range};
+use crate::helper::range_set::RangeSet;
+use crate::{erase, read_to_string, split_sections, DynSolution, Error, Input, Section, Solution};
+use std::collections::HashSet;
+use std::str::FromStr;
+
+pub const DAY: u8 = 5;
+
+type Ingredient = usize;
+
+pub struct Day5 {
+    fresh_ids: RangeSet<Ingredient>,
+    ingredient_ids: Vec<Ingredient>,
+}
+
+impl Day5 {
+    /// Build from the fresh ingredient ranges, and the available ingredient ids after them.
+    fn from_sections(sections: impl Iterator<Item = Section>) -> Result<Self, Error> {
+        let sections = sections.collect::<Vec<_>>();
+        let [fresh, available] = sections.as_slice() else {
+            let line = sections.last().map_or(0, |section| section.line);
+            return Err(Error::invalid_line(
+                line,
+                format!(
+                    "Expected fresh ranges and ingredient ids in 2 sections, found {}",
+                    sections.len()
+                ),
+            ));
+        };
+
+        let fresh_ids = parse_at(fresh.line, &fresh.text, lines(range()))?;
+        let ingredient_ids = parse_at(available.line, &available.text, lines(int()))?;
+        Ok(Day5 {
+            fresh_ids: fresh_ids.into_iter().collect(),
+            ingredient_ids,
+        })
+    }
+}
+
+impl Solution<usize, usize> for Day5 {
+    fn part1(&self) -> usize {
+        // An ingredient listed more than once is only counted once
+        self.ingredient_ids
+            .iter()
+            .filter(|&&ingredient| self.fresh_ids.contains(ingredient))
+            .collect::<HashSet<_>>()
+            .len()
+    }
+
+    fn part2(&self) -> usize {
+        // The ranges were merged as they were read, so none of them overlap
+        self.fresh_ids.len()
+    }
+}
+
+impl TryFrom<Input> for Day5 {
+    type Error = Error;
+
+    fn try_from(value: Input) -> Result<Self, Self::Error> {
+        read_to_string(&value)?.parse()
+    }
+}
+
+impl FromStr for Day5 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Day5::from_sections(split_sections(s))
+    }
+}
+
+pub fn solution(input: Input) -> Result<Box<dyn DynSolution>, Error> {
+    Ok(erase(Day5::try_from(input)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::year_2025::YEAR;
+
+    #[test]
+    fn part_1() {
+        let day_sample = Day5::try_from(Input::Sample(YEAR, DAY)).unwrap();
+        assert_eq!(3, day_sample.part1());
+    }
+
+    #[test]
+    fn part_2() {
+        let day_sample = Day5::try_from(Input::Sample(YEAR, DAY)).unwrap();
+        assert_eq!(14, day_sample.part2());
+    }
+
+    #[test]
+    fn range_bounds_are_inclusive() {
+        let day = "3-5\n1-1\n4-9\n\n1\n3\n9\n10".parse::<Day5>().unwrap();
+        assert_eq!(3, day.part1());
+        assert_eq!(8, day.part2());
+
+        let day = "3-5\r\n4-9\r\n \r\n1\r\n9\r\n".parse::<Day5>().unwrap();
+        assert_eq!((1, 7), (day.part1(), day.part2()));
+    }
+
+    #[test]
+    fn repeated_ingredients_count_once() {
+        let day = "1-5\n4-6\n\n3\n5\n3\n9".parse::<Day5>().unwrap();
+        assert_eq!(2, day.part1());
+    }
+
+    #[test]
+    fn errors_point_at_the_line() {
+        let err = "3-5\n\n1\nx".parse::<Day5>().err().unwrap();
+        assert_eq!(
+            "Line 4, column 1: Expected a number, found \"x\"",
+            err.to_string()
+        );
+        assert!("3-5\n1-2".parse::<Day5>().is_err());
+    }
+}
@@ -1,7 +1,8 @@
-use crate::{Input, Solution};
+use crate::{erase, read_to_string, DynSolution, Error, Input, Solution};
 use std::ops::{Add, Mul};
+use std::str::FromStr;
 
-const DAY: u8 = 6;
+pub const DAY: u8 = 6;
 
 type ReducedRow = usize;
 type ReducedColumn = usize;
@@ -9,26 +10,41 @@ type ReduceFn = fn(usize, usize) -> usize;
 
 struct Column((ReducedRow, ReducedColumn));
 
-impl From<&[&str]> for Column {
-    fn from(lines: &[&str]) -> Self {
+impl TryFrom<&[&str]> for Column {
+    type Error = Error;
+
+    fn try_from(lines: &[&str]) -> Result<Self, Self::Error> {
+        let operator_line = lines.len() - 1;
         let reduce_fn: ReduceFn = {
-            let reduce_symbol = lines.last().unwrap().trim();
+            let reduce_symbol = lines[operator_line].trim();
             match reduce_symbol {
                 "*" => usize::mul,
                 "+" => usize::add,
-                _ => panic!("Unknown Symbol: {reduce_symbol}"),
+                _ => {
+                    return Err(Error::invalid_line(
+                        operator_line,
+                        format!("Unknown Symbol: {reduce_symbol}"),
+                    ));
+                }
             }
         };
 
-        let value_lines = &lines[0..lines.len() - 1];
+        let value_lines = &lines[0..operator_line];
         let reduced_row: ReducedRow = value_lines
             .iter()
-            .map(|l| l.trim().parse().unwrap())
+            .enumerate()
+            .map(|(i, l)| {
+                l.trim()
+                    .parse()
+                    .map_err(|_| Error::invalid_line(i, format!("Could not parse {l:?}")))
+            })
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
             .reduce(reduce_fn)
             .unwrap_or(0);
         let reduced_col: ReducedColumn = parse_column_wise_and_reduce(value_lines, reduce_fn);
 
-        Self((reduced_row, reduced_col))
+        Ok(Self((reduced_row, reduced_col)))
     }
 }
 
@@ -44,11 +60,26 @@ impl Solution<usize, usize> for Day6 {
     }
 }
 
-impl From<Input> for Day6 {
-    fn from(value: Input) -> Self {
+impl TryFrom<Input> for Day6 {
+    type Error = Error;
+
+    fn try_from(value: Input) -> Result<Self, Self::Error> {
+        read_to_string(&value)?.parse()
+    }
+}
+
+impl FromStr for Day6 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
         let columns = {
-            let string = std::fs::read_to_string(value.to_string()).unwrap();
-            let lines = string.lines().collect::<Vec<&str>>();
+            let lines = s.lines().collect::<Vec<&str>>();
+            if lines.len() < 2 {
+                return Err(Error::invalid_line(
+                    lines.len(),
+                    "Expected rows of values followed by a row of operators",
+                ));
+            }
 
             let mut columns = vec![];
             let mut starting_column = 0;
@@ -60,29 +91,42 @@ impl From<Input> for Day6 {
                 }
 
                 // Get all values in the current problem between the starting and ending column.
-                let values = lines
-                    .iter()
-                    .map(|line| &line[starting_column..i - 1])
-                    .collect::<Vec<&str>>();
-                columns.push(Column::from(values.as_slice()));
+                let values = column_values(&lines, starting_column, Some(i - 1))?;
+                columns.push(Column::try_from(values.as_slice())?);
                 starting_column = i;
             }
 
             // Account for the last column which won't have an ending space.
             {
-                let values = lines
-                    .iter()
-                    .map(|line| &line[starting_column..])
-                    .collect::<Vec<&str>>();
-                columns.push(Column::from(values.as_slice()));
+                let values = column_values(&lines, starting_column, None)?;
+                columns.push(Column::try_from(values.as_slice())?);
             }
             columns
         };
 
-        Day6(columns)
+        Ok(Day6(columns))
     }
 }
 
+/// Slice each of the lines between the start and (optional) end column.
+fn column_values<'a>(
+    lines: &[&'a str],
+    start: usize,
+    end: Option<usize>,
+) -> Result<Vec<&'a str>, Error> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            let value = match end {
+                Some(end) => line.get(start..end),
+                None => line.get(start..),
+            };
+            value.ok_or_else(|| Error::invalid_line(i, "Line is shorter than the operator line"))
+        })
+        .collect()
+}
+
 /// Read the values in the columns from right-to-left and return them as a vector.
 ///
 /// For example:
@@ -127,26 +171,24 @@ fn parse_column_wise_and_reduce(lines: &[&str], reduce_fn: ReduceFn) -> ReducedC
         .unwrap_or(0)
 }
 
-pub fn run() {
-    let day = Day6::from(Input::Part1(DAY));
-    day.report(DAY);
+pub fn solution(input: Input) -> Result<Box<dyn DynSolution>, Error> {
+    Ok(erase(Day6::try_from(input)?))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::year_2025::YEAR;
 
     #[test]
     fn part_1() {
-        let day_sample = Day6::from(Input::Sample(DAY));
+        let day_sample = Day6::try_from(Input::Sample(YEAR, DAY)).unwrap();
         assert_eq!(4_277_556, day_sample.part1());
-        assert_eq!(6_299_564_383_938, 6_299_564_383_938_usize);
     }
 
     #[test]
     fn part_2() {
-        let day_sample = Day6::from(Input::Sample(DAY));
+        let day_sample = Day6::try_from(Input::Sample(YEAR, DAY)).unwrap();
         assert_eq!(3_263_827, day_sample.part2());
-        assert_eq!(11_950_004_808_442, 11_950_004_808_442_usize);
     }
 }
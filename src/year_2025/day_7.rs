@@ -0,0 +1,118 @@
+use crate::points::direction::Direction;
+use crate::points::grid::Grid;
+use crate::points::point_2d::Point2D;
+use crate::{erase, read_to_string, DynSolution, Error, Input, Solution};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::str::FromStr;
+
+pub const DAY: u8 = 7;
+
+type TachyonSplitters = Point2D<i64>;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Empty,
+    Start,
+    Splitter,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = ();
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Tile::Empty),
+            'S' => Ok(Tile::Start),
+            '^' => Ok(Tile::Splitter),
+            _ => Err(()),
+        }
+    }
+}
+
+pub struct Day7 {
+    start: TachyonSplitters,
+    manifold: Grid<Tile>,
+}
+
+impl Solution<usize, usize> for Day7 {
+    fn part1(&self) -> usize {
+        let mut heap = BinaryHeap::new();
+        heap.push(self.start);
+        let mut visited = HashSet::new();
+        while let Some(beam) = heap.pop() {
+            let after = (beam.row()..)
+                .map(|row| TachyonSplitters::new(row, beam.col()))
+                .take_while(|&next| self.manifold.contains(next))
+                .find(|&next| self.manifold[next] == Tile::Splitter);
+
+            if let Some(next) = after
+                && visited.insert(next)
+            {
+                heap.push(next.step(Direction::East));
+                heap.push(next.step(Direction::West));
+            }
+        }
+
+        visited.len()
+    }
+
+    fn part2(&self) -> usize {
+        let mut beams = HashMap::new();
+        beams.insert(self.start.col(), 1);
+        for row in self.manifold.rows() {
+            for (col, tile) in row.iter().enumerate() {
+                if *tile != Tile::Splitter {
+                    continue;
+                }
+                let col = i64::try_from(col).unwrap();
+
+                if let Some(count) = beams.remove(&col) {
+                    *beams.entry(col - 1).or_insert(0) += count;
+                    *beams.entry(col + 1).or_insert(0) += count;
+                }
+            }
+        }
+
+        beams.into_values().sum()
+    }
+}
+
+impl TryFrom<Input> for Day7 {
+    type Error = Error;
+
+    fn try_from(value: Input) -> Result<Self, Self::Error> {
+        read_to_string(&value)?.parse()
+    }
+}
+
+impl FromStr for Day7 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let manifold = s.parse::<Grid<Tile>>()?;
+        let start = manifold.find(&Tile::Start).unwrap_or_default();
+        Ok(Day7 { start, manifold })
+    }
+}
+
+pub fn solution(input: Input) -> Result<Box<dyn DynSolution>, Error> {
+    Ok(erase(Day7::try_from(input)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::year_2025::YEAR;
+
+    #[test]
+    fn part_1() {
+        let day_sample = Day7::try_from(Input::Sample(YEAR, DAY)).unwrap();
+        assert_eq!(21, day_sample.part1());
+    }
+
+    #[test]
+    fn part_2() {
+        let day_sample = Day7::try_from(Input::Sample(YEAR, DAY)).unwrap();
+        assert_eq!(40, day_sample.part2());
+    }
+}
@@ -1,12 +1,13 @@
 use crate::points::point_3d::Point3D;
-use crate::{read_to_iter, Input, Solution};
+use crate::{erase, read_to_string, DynSolution, Error, Input, Solution};
 use std::cmp::Reverse;
 use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::str::FromStr;
 
-const DAY: u8 = 8;
+pub const DAY: u8 = 8;
 
 type JunctionBox = Point3D<i64>;
-type Distance = usize;
+type Distance = u128;
 type MinHeap<T> = BinaryHeap<Reverse<T>>;
 
 pub struct Day8<const L1: usize = 0> {
@@ -20,7 +21,7 @@ impl<const L1: usize> Day8<L1> {
             .map(|(a, b)| {
                 let p1 = self.boxes[a];
                 let p2 = self.boxes[b];
-                Reverse((p1.distance(p2), (p1, p2)))
+                Reverse((p1.squared_distance(p2), (p1, p2)))
             })
             .collect::<MinHeap<_>>()
     }
@@ -88,7 +89,7 @@ impl<const L1: usize> Solution<usize, usize> for Day8<L1> {
             if let Some(location) = circuit
                 && connections[location].len() == self.boxes.len()
             {
-                return usize::try_from(start.x * end.x).unwrap_or(0);
+                return usize::try_from(start.x() * end.x()).unwrap_or(0);
             }
         }
 
@@ -96,13 +97,24 @@ impl<const L1: usize> Solution<usize, usize> for Day8<L1> {
     }
 }
 
-impl<const L1: usize> From<Input> for Day8<L1> {
-    fn from(value: Input) -> Self {
-        let boxes = read_to_iter(&value)
-            .unwrap()
-            .flat_map(JunctionBox::try_from)
-            .collect::<Vec<_>>();
-        Day8::<L1> { boxes }
+impl<const L1: usize> TryFrom<Input> for Day8<L1> {
+    type Error = Error;
+
+    fn try_from(value: Input) -> Result<Self, Self::Error> {
+        read_to_string(&value)?.parse()
+    }
+}
+
+impl<const L1: usize> FromStr for Day8<L1> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let boxes = s
+            .lines()
+            .enumerate()
+            .map(|(i, line)| JunctionBox::try_from(line).map_err(|err| Error::invalid_line(i, err)))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Day8::<L1> { boxes })
     }
 }
 
@@ -143,26 +155,31 @@ fn generate_connections(
     light_strings
 }
 
-pub fn run() {
-    let day = Day8::<1000>::from(Input::Part1(DAY));
-    day.report(DAY);
+pub fn solution(input: Input) -> Result<Box<dyn DynSolution>, Error> {
+    // The number of connections to make in part 1 differs between the sample and real input.
+    // Inputs from a path or stdin are assumed to be real, as nothing says which they are.
+    match input {
+        Input::Sample(..) | Input::Sample2(..) => Ok(erase(Day8::<10>::try_from(input)?)),
+        Input::Part1(..) | Input::Part2(..) | Input::Path(_) | Input::Stdin => {
+            Ok(erase(Day8::<1000>::try_from(input)?))
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::year_2025::YEAR;
 
     #[test]
     fn part_1() {
-        let day_sample = Day8::<10>::from(Input::Sample(DAY));
+        let day_sample = Day8::<10>::try_from(Input::Sample(YEAR, DAY)).unwrap();
         assert_eq!(40, day_sample.part1());
-        assert_eq!(66912, 66912);
     }
 
     #[test]
     fn part_2() {
-        let day_sample = Day8::<10>::from(Input::Sample(DAY));
+        let day_sample = Day8::<10>::try_from(Input::Sample(YEAR, DAY)).unwrap();
         assert_eq!(25272, day_sample.part2());
-        assert_eq!(724_454_082, 724_454_082);
     }
 }
@@ -1,11 +1,12 @@
 use crate::points::point_2d::Point2D;
-use crate::{read_to_iter, Input, Solution};
+use crate::{erase, read_to_string, DynSolution, Error, Input, Solution};
 use std::collections::BinaryHeap;
+use std::str::FromStr;
 
-const DAY: u8 = 9;
+pub const DAY: u8 = 9;
 
 type Light = Point2D<i32>;
-type Distance = usize;
+type Area = usize;
 type RowMin = i32;
 type RowMax = i32;
 type ColMin = i32;
@@ -15,29 +16,24 @@ type ColMax = i32;
 struct LightPair((Light, Light));
 
 impl LightPair {
-    fn area(&self) -> usize {
-        let width = (self.0.1.col - self.0.0.col).unsigned_abs() as usize + 1;
-        let height = (self.0.1.row - self.0.0.row).unsigned_abs() as usize + 1;
+    fn area(&self) -> Area {
+        let (a, b) = self.0;
+        let width = a.col().abs_diff(b.col()) as usize + 1;
+        let height = a.row().abs_diff(b.row()) as usize + 1;
         width * height
     }
 
     fn corners(&self) -> ((RowMin, RowMax), (ColMin, ColMax)) {
-        let row = (
-            self.0.0.row.min(self.0.1.row),
-            self.0.0.row.max(self.0.1.row),
-        );
-        let col = (
-            self.0.0.col.min(self.0.1.col),
-            self.0.0.col.max(self.0.1.col),
-        );
-        (row, col)
+        let min = self.0.0.component_min(self.0.1);
+        let max = self.0.0.component_max(self.0.1);
+        ((min.row(), max.row()), (min.col(), max.col()))
     }
 }
 
 pub struct Day9(Vec<Light>);
 
 impl Day9 {
-    fn pairs(&self) -> BinaryHeap<(Distance, LightPair)> {
+    fn pairs(&self) -> BinaryHeap<(Area, LightPair)> {
         (0..self.0.len())
             .flat_map(|a| (a + 1..self.0.len()).map(move |b| (a, b)))
             .map(|(a, b)| {
@@ -69,18 +65,20 @@ impl Solution<usize, usize> for Day9 {
                 for (i, start) in self.0.iter().enumerate() {
                     let end = self.0[(i + 1) % self.0.len()];
 
-                    if start.row == end.row {
-                        let (col_start, col_end) = (start.col.min(end.col), start.col.max(end.col));
-                        if row_min < start.row
-                            && row_max > start.row
+                    if start.row() == end.row() {
+                        let (col_start, col_end) =
+                            (start.col().min(end.col()), start.col().max(end.col()));
+                        if row_min < start.row()
+                            && row_max > start.row()
                             && !(col_min >= col_end || col_max <= col_start)
                         {
                             return None;
                         }
-                    } else if start.col == end.col {
-                        let (row_start, row_end) = (start.row.min(end.row), start.row.max(end.row));
-                        if col_min < start.col
-                            && col_max > start.col
+                    } else if start.col() == end.col() {
+                        let (row_start, row_end) =
+                            (start.row().min(end.row()), start.row().max(end.row()));
+                        if col_min < start.col()
+                            && col_max > start.col()
                             && !(row_min >= row_end || row_max <= row_start)
                         {
                             return None;
@@ -98,36 +96,51 @@ impl Solution<usize, usize> for Day9 {
     }
 }
 
-impl From<Input> for Day9 {
-    fn from(value: Input) -> Self {
-        let lights = read_to_iter(&value)
-            .unwrap()
-            .flat_map(Light::try_from)
-            .collect::<Vec<Light>>();
-        Day9(lights)
+impl TryFrom<Input> for Day9 {
+    type Error = Error;
+
+    fn try_from(value: Input) -> Result<Self, Self::Error> {
+        read_to_string(&value)?.parse()
+    }
+}
+
+impl FromStr for Day9 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lights = s
+            .lines()
+            .enumerate()
+            .map(|(i, line)| Light::try_from(line).map_err(|err| Error::invalid_line(i, err)))
+            .collect::<Result<Vec<Light>, _>>()?;
+        Ok(Day9(lights))
     }
 }
 
-pub fn run() {
-    let day = Day9::from(Input::Part1(DAY));
-    day.report(DAY);
+pub fn solution(input: Input) -> Result<Box<dyn DynSolution>, Error> {
+    Ok(erase(Day9::try_from(input)?))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::year_2025::YEAR;
 
     #[test]
     fn part_1() {
-        let day_sample = Day9::from(Input::Sample(DAY));
+        let day_sample = Day9::try_from(Input::Sample(YEAR, DAY)).unwrap();
         assert_eq!(50, day_sample.part1());
-        assert_eq!(4_738_108_384, 4_738_108_384_usize);
+    }
+
+    #[test]
+    fn area_of_distant_lights() {
+        let pair = LightPair((Light::new(0, i32::MIN), Light::new(1, i32::MAX)));
+        assert_eq!(2 * (u32::MAX as usize + 1), pair.area());
     }
 
     #[test]
     fn part_2() {
-        let day_sample = Day9::from(Input::Sample(DAY));
+        let day_sample = Day9::try_from(Input::Sample(YEAR, DAY)).unwrap();
         assert_eq!(24, day_sample.part2());
-        assert_eq!(1_513_792_010, 1_513_792_010);
     }
 }
@@ -0,0 +1,33 @@
+use crate::registry::Entry;
+use crate::Year;
+
+pub mod day_1;
+pub mod day_10;
+pub mod day_11;
+pub mod day_12;
+pub mod day_2;
+pub mod day_3;
+pub mod day_4;
+pub mod day_5;
+pub mod day_6;
+pub mod day_7;
+pub mod day_8;
+pub mod day_9;
+
+pub const YEAR: Year = 2025;
+
+/// All solved days of the year, ordered by [`Day`](crate::Day).
+pub const DAYS: &[Entry] = &[
+    Entry::new(YEAR, day_1::DAY, day_1::solution),
+    Entry::new(YEAR, day_2::DAY, day_2::solution),
+    Entry::new(YEAR, day_3::DAY, day_3::solution),
+    Entry::new(YEAR, day_4::DAY, day_4::solution),
+    Entry::new(YEAR, day_5::DAY, day_5::solution),
+    Entry::new(YEAR, day_6::DAY, day_6::solution),
+    Entry::new(YEAR, day_7::DAY, day_7::solution),
+    Entry::new(YEAR, day_8::DAY, day_8::solution),
+    Entry::new(YEAR, day_9::DAY, day_9::solution),
+    Entry::new(YEAR, day_10::DAY, day_10::solution),
+    Entry::new(YEAR, day_11::DAY, day_11::solution),
+    Entry::new(YEAR, day_12::DAY, day_12::solution),
+];